@@ -274,6 +274,17 @@ impl pallet_infimum::Config for Runtime {
 	type MaxVoteOptions = ConstU32<32>;
 	type MaxPollRegistrations = ConstU32<65536>;
 	type MaxPollInteractions = ConstU32<65536>;
+	type MaxIterationDepth = ConstU32<16>;
+	type RejectCoordinatorKeyAsParticipant = ConstBool<false>;
+	type RejectEphemeralKeyReuse = ConstBool<false>;
+	type RequireRegisteredInteractions = ConstBool<false>;
+	type MinSignupPeriod = ConstU64<0>;
+	type MinVotingPeriod = ConstU64<0>;
+	type PollCreationCooldown = ConstU64<0>;
+	type MaxMetadataLen = ConstU32<128>;
+	type MaxDepthKeys = ConstU32<8>;
+	type CoordinatorOrigin = frame_system::EnsureSigned<AccountId>;
+	type WeightInfo = ();
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -506,6 +517,51 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_infimum::prelude::InfimumApi<Block> for Runtime {
+		fn proof_public_inputs(
+			poll_id: pallet_infimum::prelude::PollId,
+			proof_index: pallet_infimum::prelude::CommitmentIndex,
+			curr_commitment: pallet_infimum::prelude::HashBytes,
+			new_commitment: pallet_infimum::prelude::HashBytes,
+		) -> Option<Vec<pallet_infimum::prelude::HashBytes>> {
+			Infimum::proof_public_inputs(poll_id, proof_index, curr_commitment, new_commitment)
+		}
+
+		fn preview_registration_root(
+			poll_id: pallet_infimum::prelude::PollId,
+		) -> Option<pallet_infimum::prelude::HashBytes> {
+			Infimum::preview_registration_root(poll_id)
+		}
+
+		fn poseidon_params_digest(width: u8) -> Option<pallet_infimum::prelude::HashBytes> {
+			Infimum::poseidon_params_digest(width)
+		}
+
+		fn registration_state_depth(poll_id: pallet_infimum::prelude::PollId) -> Option<u8> {
+			Infimum::registration_state_depth(poll_id)
+		}
+
+		fn vote_options(poll_id: pallet_infimum::prelude::PollId) -> Option<Vec<u128>> {
+			Infimum::vote_options(poll_id)
+		}
+
+		fn polls_by_status(
+			status: pallet_infimum::prelude::PollStatus,
+			start: pallet_infimum::prelude::PollId,
+			max: u32,
+		) -> (Vec<pallet_infimum::prelude::PollId>, Option<pallet_infimum::prelude::PollId>) {
+			Infimum::polls_by_status(status, start, max)
+		}
+
+		fn verify_proof_with_key(
+			verify_key: pallet_infimum::prelude::VerifyKey,
+			public_inputs: Vec<pallet_infimum::prelude::HashBytes>,
+			proof: pallet_infimum::prelude::ProofData,
+		) -> bool {
+			Infimum::verify_proof_with_key(verify_key, public_inputs, proof)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
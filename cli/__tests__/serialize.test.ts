@@ -0,0 +1,51 @@
+import inf from 'inf-lib';
+import { importSnarkjsProof, readJSON } from '../src';
+
+// Reads a little-endian field element from a serialized point, as written by arkworks.
+const fieldFromBytes = (bytes: number[], offset: number): string =>
+{
+    return bytes
+        .slice(offset, offset + 32)
+        .reduceRight((value, byte) => value * BigInt(256) + BigInt(byte), BigInt(0))
+        .toString();
+};
+
+describe("snarkjs proof import", function test()
+{
+    const FIXTURE = '../__tests__/fixtures/proof.json';
+
+    it("Should round trip the fixture proof coordinates", () =>
+    {
+        const proof = readJSON(FIXTURE);
+        const serialized = importSnarkjsProof(FIXTURE);
+
+        expect(serialized.pi_a.length).toBe(64);
+        expect([ fieldFromBytes(serialized.pi_a, 0), fieldFromBytes(serialized.pi_a, 32) ]).toEqual(proof.pi_a.slice(0, 2));
+
+        expect(serialized.pi_b.length).toBe(128);
+        expect([
+            [ fieldFromBytes(serialized.pi_b, 0), fieldFromBytes(serialized.pi_b, 32) ],
+            [ fieldFromBytes(serialized.pi_b, 64), fieldFromBytes(serialized.pi_b, 96) ]
+        ]).toEqual(proof.pi_b.slice(0, 2));
+
+        expect(serialized.pi_c.length).toBe(64);
+        expect([ fieldFromBytes(serialized.pi_c, 0), fieldFromBytes(serialized.pi_c, 32) ]).toEqual(proof.pi_c.slice(0, 2));
+
+        // The envelope is ignored by the plain proof serialization, which must agree.
+        expect(inf.serialize_proof(proof)).toEqual(serialized);
+    });
+
+    it("Should reject malformed proofs rather than panicking", () =>
+    {
+        const proof = readJSON(FIXTURE);
+        const swapped = { ...proof, pi_b: proof.pi_b.map(([ c0, c1 ]: string[]) => [ c1, c0 ]) };
+        const malformed = { ...proof, pi_a: [ "one", "2", "1" ] };
+        const oversized = { ...proof, pi_c: [ "1", "2".repeat(80), "1" ] };
+
+        expect(() => inf.import_snarkjs_proof("{")).toThrow();
+        expect(() => inf.import_snarkjs_proof(JSON.stringify(swapped))).toThrow();
+        expect(() => inf.import_snarkjs_proof(JSON.stringify(malformed))).toThrow();
+        expect(() => inf.import_snarkjs_proof(JSON.stringify(oversized))).toThrow();
+        expect(() => inf.import_snarkjs_proof(JSON.stringify({ ...proof, protocol: "plonk" }))).toThrow();
+    });
+});
@@ -15,7 +15,7 @@ use ark_bn254::{
 };
 use ark_ff::{
     BigInteger256, 
-    // PrimeField
+    PrimeField
 };
 use ark_serialize::{
     CanonicalSerialize, 
@@ -62,6 +62,52 @@ pub fn bn_to_bytes_be(input_js: JsValue) -> Result<JsValue, JsError>
     Ok(serde_wasm_bindgen::to_value(&output).unwrap())
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct PublicKeyByteVector
+{
+    pub x: Vec<u8>,
+    pub y: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PublicKeyBigNumber
+{
+    pub x: String,
+    pub y: String,
+}
+
+fn bn_to_bytes_be_padded(value: &str) -> Vec<u8>
+{
+    let bytes = BigUint::from_str_radix(value, 10).unwrap().to_bytes_be();
+    let mut padded = vec![0u8; 32usize.saturating_sub(bytes.len())];
+    padded.extend(bytes);
+    padded
+}
+
+#[wasm_bindgen]
+pub fn pubkey_to_bn(pk_js: JsValue) -> Result<JsValue, JsError>
+{
+    let pk: PublicKeyByteVector = serde_wasm_bindgen::from_value(pk_js).unwrap();
+    let output = PublicKeyBigNumber {
+        x: BigUint::from_bytes_be(&pk.x).to_string(),
+        y: BigUint::from_bytes_be(&pk.y).to_string()
+    };
+
+    Ok(serde_wasm_bindgen::to_value(&output).unwrap())
+}
+
+#[wasm_bindgen]
+pub fn bn_to_pubkey(pk_bn_js: JsValue) -> Result<JsValue, JsError>
+{
+    let pk_bn: PublicKeyBigNumber = serde_wasm_bindgen::from_value(pk_bn_js).unwrap();
+    let output = PublicKeyByteVector {
+        x: bn_to_bytes_be_padded(&pk_bn.x),
+        y: bn_to_bytes_be_padded(&pk_bn.y)
+    };
+
+    Ok(serde_wasm_bindgen::to_value(&output).unwrap())
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct VerifyingKeyBigNumber
 {
@@ -90,6 +136,17 @@ pub struct ProofBigNumber
     pub pi_c: [String; 3],
 }
 
+/// The `proof.json` file emitted by `snarkjs groth16 prove`.
+#[derive(Serialize, Deserialize)]
+pub struct SnarkjsProof
+{
+    pub pi_a: [String; 3],
+    pub pi_b: [[String; 2]; 3],
+    pub pi_c: [String; 3],
+    pub protocol: String,
+    pub curve: String,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ProofByteVector
 {
@@ -105,39 +162,51 @@ fn serialize_point_to_bytes<T: CanonicalSerialize>(point: &T) -> Vec<u8>
     bytes
 }
 
-fn fq_from_str(s: &str) -> Fq
+fn fq_from_str(s: &str) -> Result<Fq, JsError>
 {
-    BigInteger256::try_from(BigUint::from_str(s).unwrap())
-        .unwrap()
-        .into()
+    let value = BigUint::from_str(s).map_err(|_| JsError::new("field element is not a decimal integer"))?;
+    let value = BigInteger256::try_from(value).map_err(|_| JsError::new("field element exceeds 256 bits"))?;
+
+    Fq::from_bigint(value).ok_or_else(|| JsError::new("field element exceeds the field modulus"))
 }
 
-fn g1_bn_to_bytes(els: [String; 3]) -> Vec<u8>
+fn g1_bn_to_bytes(els: [String; 3]) -> Result<Vec<u8>, JsError>
 {
-    let g1 = G1Affine::from(G1Projective::new(
-        fq_from_str(&els[0]),
-        fq_from_str(&els[1]),
-        fq_from_str(&els[2]),
+    let g1 = G1Affine::from(G1Projective::new_unchecked(
+        fq_from_str(&els[0])?,
+        fq_from_str(&els[1])?,
+        fq_from_str(&els[2])?,
     ));
+    if !g1.is_on_curve() || !g1.is_in_correct_subgroup_assuming_on_curve()
+    {
+        return Err(JsError::new("point is not a valid G1 point"));
+    }
 
-    serialize_point_to_bytes(&g1)
+    Ok(serialize_point_to_bytes(&g1))
 }
 
-fn g1_bn_vec_to_bytes(els: Vec<[String; 3]>) -> Vec<Vec<u8>> 
+fn g1_bn_vec_to_bytes(els: Vec<[String; 3]>) -> Result<Vec<Vec<u8>>, JsError>
 {
     els.into_iter()
         .map(g1_bn_to_bytes)
         .collect()
 }
 
-fn g2_bn_to_bytes(els: [[String; 2]; 3]) -> Vec<u8>
+/// The G2 coordinates are expected as written by snarkjs, i.e. `[[x.c0, x.c1], [y.c0, y.c1], [z.c0, z.c1]]`, which is
+/// the order expected by `Fq2::new`. NB the (reversed) `[c1, c0]` order used by the snarkjs solidity calldata export
+/// must not be supplied here, since a swapped point is rejected as not being on the curve.
+fn g2_bn_to_bytes(els: [[String; 2]; 3]) -> Result<Vec<u8>, JsError>
 {
-    let x = Fq2::new(fq_from_str(&els[0][0]), fq_from_str(&els[0][1]));
-    let y = Fq2::new(fq_from_str(&els[1][0]), fq_from_str(&els[1][1]));
-    let z = Fq2::new(fq_from_str(&els[2][0]), fq_from_str(&els[2][1]));
-    let g2 = G2Affine::from(G2Projective::new(x, y, z));
+    let x = Fq2::new(fq_from_str(&els[0][0])?, fq_from_str(&els[0][1])?);
+    let y = Fq2::new(fq_from_str(&els[1][0])?, fq_from_str(&els[1][1])?);
+    let z = Fq2::new(fq_from_str(&els[2][0])?, fq_from_str(&els[2][1])?);
+    let g2 = G2Affine::from(G2Projective::new_unchecked(x, y, z));
+    if !g2.is_on_curve() || !g2.is_in_correct_subgroup_assuming_on_curve()
+    {
+        return Err(JsError::new("point is not a valid G2 point"));
+    }
 
-    serialize_point_to_bytes(&g2)
+    Ok(serialize_point_to_bytes(&g2))
 }
 
 #[wasm_bindgen]
@@ -148,11 +217,11 @@ pub fn serialize_vkey(
     let vkey_bn: VerifyingKeyBigNumber = serde_wasm_bindgen::from_value(vkey_js).unwrap();
 
     let vkey = VerifyingKeyByteVector {
-        alpha_g1: g1_bn_to_bytes(vkey_bn.vk_alpha_1),
-        beta_g2: g2_bn_to_bytes(vkey_bn.vk_beta_2),
-        gamma_g2: g2_bn_to_bytes(vkey_bn.vk_gamma_2),
-        delta_g2: g2_bn_to_bytes(vkey_bn.vk_delta_2),
-        gamma_abc_g1: g1_bn_vec_to_bytes(vkey_bn.ic)
+        alpha_g1: g1_bn_to_bytes(vkey_bn.vk_alpha_1)?,
+        beta_g2: g2_bn_to_bytes(vkey_bn.vk_beta_2)?,
+        gamma_g2: g2_bn_to_bytes(vkey_bn.vk_gamma_2)?,
+        delta_g2: g2_bn_to_bytes(vkey_bn.vk_delta_2)?,
+        gamma_abc_g1: g1_bn_vec_to_bytes(vkey_bn.ic)?
     };
 
     Ok(serde_wasm_bindgen::to_value(&vkey).unwrap())
@@ -166,14 +235,36 @@ pub fn serialize_proof(
     let proof_bn: ProofBigNumber = serde_wasm_bindgen::from_value(proof_js).unwrap();
 
     let proof = ProofByteVector {
-        pi_a: g1_bn_to_bytes(proof_bn.pi_a),
-        pi_b: g2_bn_to_bytes(proof_bn.pi_b),
-        pi_c: g1_bn_to_bytes(proof_bn.pi_c),
+        pi_a: g1_bn_to_bytes(proof_bn.pi_a)?,
+        pi_b: g2_bn_to_bytes(proof_bn.pi_b)?,
+        pi_c: g1_bn_to_bytes(proof_bn.pi_c)?,
     };
 
     Ok(serde_wasm_bindgen::to_value(&proof).unwrap())
 }
 
+/// Serializes the contents of a snarkjs `proof.json` file. See `g2_bn_to_bytes` for the expected G2 coordinate order.
+#[wasm_bindgen]
+pub fn import_snarkjs_proof(
+    json_str: &str
+) -> Result<JsValue, JsError>
+{
+    let json = js_sys::JSON::parse(json_str).map_err(|_| JsError::new("proof is not valid json"))?;
+    let proof: SnarkjsProof = serde_wasm_bindgen::from_value(json)
+        .map_err(|error| JsError::new(&format!("proof is malformed: {}", error)))?;
+
+    if proof.protocol != "groth16" { return Err(JsError::new("proof protocol must be groth16")); }
+    if proof.curve != "bn128" { return Err(JsError::new("proof curve must be bn128")); }
+
+    let output = ProofByteVector {
+        pi_a: g1_bn_to_bytes(proof.pi_a)?,
+        pi_b: g2_bn_to_bytes(proof.pi_b)?,
+        pi_c: g1_bn_to_bytes(proof.pi_c)?,
+    };
+
+    Ok(serde_wasm_bindgen::to_value(&output).unwrap())
+}
+
 // #[derive(Serialize, Deserialize)]
 // pub struct ImageByteVector
 // {
@@ -2,6 +2,7 @@ import { ApiPromise } from '@polkadot/api';
 import { AddressOrPair } from '@polkadot/api/types';
 import { DispatchError } from '@polkadot/types/interfaces';
 import {
+    PollConfigInput,
     PollOutcome,
     ProofData,
     PublicKey,
@@ -20,6 +21,17 @@ export enum InfimumExtrinsic
     commitOutcome = 'commitOutcome',
     nullifyPoll = 'nullifyPoll',
     rotateKeys = 'rotateKeys',
+    submitVote = 'submitVote',
+    registerDepthKeys = 'registerDepthKeys',
+    prunePoll = 'prunePoll',
+    interactBatch = 'interactBatch',
+    resyncLastPoll = 'resyncLastPoll',
+    createPollV2 = 'createPollV2',
+    setPollMetadata = 'setPollMetadata',
+    rotateVerifyKey = 'rotateVerifyKey',
+    verifyOutcomeBatches = 'verifyOutcomeBatches',
+    commitOutcomeFrom = 'commitOutcomeFrom',
+    setCoordinatorMetadata = 'setCoordinatorMetadata',
 }
 
 interface InfimumExtrinsicArgs
@@ -60,7 +72,7 @@ interface InfimumExtrinsicArgs
         // vote_option_tree_depth: u8
         number, 
         // vote_options: vec::Vec<u128>
-        Array<number> 
+        Array<number>
     ];
     [InfimumExtrinsic.mergePollState]: [];
     [InfimumExtrinsic.commitOutcome]: [
@@ -76,12 +88,76 @@ interface InfimumExtrinsicArgs
         // verify_key: VerifyingKeys
         VerifyingKeys
     ];
+    [InfimumExtrinsic.submitVote]: [
+        // poll_id: PollId
+        number,
+        // public_key: PublicKey
+        PublicKey,
+        // data: PollInteractionData
+        Array<Array<number>>,
+        // vote_option_hint: Option<u32>
+        number | undefined
+    ];
+    [InfimumExtrinsic.registerDepthKeys]: [
+        // registration_depth: u8
+        number,
+        // interaction_depth: u8
+        number,
+        // verify_key: VerifyingKeys
+        VerifyingKeys
+    ];
+    [InfimumExtrinsic.prunePoll]: [
+        // poll_id: PollId
+        number
+    ];
+    [InfimumExtrinsic.interactBatch]: [
+        // poll_id: PollId
+        number,
+        // messages: PollInteractionBatch
+        Array<[ PublicKey, Array<Array<number>> ]>
+    ];
+    [InfimumExtrinsic.resyncLastPoll]: [];
+    [InfimumExtrinsic.createPollV2]: [
+        // config: PollConfigInput
+        PollConfigInput
+    ];
+    [InfimumExtrinsic.setPollMetadata]: [
+        // poll_id: PollId
+        number,
+        // metadata_uri: Option<vec::Vec<u8>>
+        Array<number> | undefined
+    ];
+    [InfimumExtrinsic.rotateVerifyKey]: [
+        // verify_key: VerifyingKeys
+        VerifyingKeys
+    ];
+    [InfimumExtrinsic.verifyOutcomeBatches]: [
+        // batches: ProofBatches
+        Array<[ ProofData, Array<number> ]>,
+        // outcome: Option<PollOutcome>
+        PollOutcome | undefined
+    ];
+    [InfimumExtrinsic.commitOutcomeFrom]: [
+        // start_index: CommitmentIndex
+        number,
+        // start_commitment: CommitmentData
+        Array<number>,
+        // batches: ProofBatches
+        Array<[ ProofData, Array<number> ]>,
+        // outcome: Option<PollOutcome>
+        PollOutcome | undefined
+    ];
+    [InfimumExtrinsic.setCoordinatorMetadata]: [
+        // metadata: Option<vec::Vec<u8>>
+        Array<number> | undefined
+    ];
 }
 
 enum InfimumDepositEvent
 {
     CoordinatorRegistered = 'CoordinatorRegistered',
     CoordinatorKeysChanged = 'CoordinatorKeysChanged',
+    CoordinatorDepthKeysChanged = 'CoordinatorDepthKeysChanged',
     ParticipantRegistered = 'ParticipantRegistered',
     PollCreated = 'PollCreated',
     PollInteraction = 'PollInteraction',
@@ -89,17 +165,35 @@ enum InfimumDepositEvent
     PollStateMerged = 'PollStateMerged',
     PollOutcome = 'PollOutcome',
     PollNullified = 'PollNullified',
+    PollProofVerified = 'PollProofVerified',
+    PollPruned = 'PollPruned',
+    PollProofBatchRejected = 'PollProofBatchRejected',
+    CoordinatorLastPollResynced = 'CoordinatorLastPollResynced',
+    PollStateTreeFilled = 'PollStateTreeFilled',
+    PollMetadataUpdated = 'PollMetadataUpdated',
+    CoordinatorMetadataUpdated = 'CoordinatorMetadataUpdated',
 }
 
 type InfimumExtrinsicEvents = {
     [InfimumExtrinsic.registerAsCoordinator]: [InfimumDepositEvent.CoordinatorRegistered],
-    [InfimumExtrinsic.registerAsParticipant]: [InfimumDepositEvent.ParticipantRegistered],
-    [InfimumExtrinsic.interactWithPoll]: [InfimumDepositEvent.PollInteraction],
+    [InfimumExtrinsic.registerAsParticipant]: [InfimumDepositEvent.ParticipantRegistered, InfimumDepositEvent.PollStateTreeFilled],
+    [InfimumExtrinsic.interactWithPoll]: [InfimumDepositEvent.PollInteraction, InfimumDepositEvent.PollStateTreeFilled],
     [InfimumExtrinsic.createPoll]: [InfimumDepositEvent.PollCreated],
     [InfimumExtrinsic.mergePollState]: [InfimumDepositEvent.PollStateMerged],
-    [InfimumExtrinsic.commitOutcome]: [InfimumDepositEvent.PollCommitmentUpdated, InfimumDepositEvent.PollOutcome],
+    [InfimumExtrinsic.commitOutcome]: [InfimumDepositEvent.PollCommitmentUpdated, InfimumDepositEvent.PollOutcome, InfimumDepositEvent.PollProofBatchRejected],
     [InfimumExtrinsic.nullifyPoll]: [InfimumDepositEvent.PollNullified],
-    [InfimumExtrinsic.rotateKeys]: [InfimumDepositEvent.CoordinatorKeysChanged]
+    [InfimumExtrinsic.rotateKeys]: [InfimumDepositEvent.CoordinatorKeysChanged],
+    [InfimumExtrinsic.submitVote]: [InfimumDepositEvent.PollInteraction, InfimumDepositEvent.PollStateTreeFilled],
+    [InfimumExtrinsic.registerDepthKeys]: [InfimumDepositEvent.CoordinatorDepthKeysChanged],
+    [InfimumExtrinsic.prunePoll]: [InfimumDepositEvent.PollPruned],
+    [InfimumExtrinsic.interactBatch]: [InfimumDepositEvent.PollInteraction, InfimumDepositEvent.PollStateTreeFilled],
+    [InfimumExtrinsic.resyncLastPoll]: [InfimumDepositEvent.CoordinatorLastPollResynced],
+    [InfimumExtrinsic.createPollV2]: [InfimumDepositEvent.PollCreated],
+    [InfimumExtrinsic.setPollMetadata]: [InfimumDepositEvent.PollMetadataUpdated],
+    [InfimumExtrinsic.rotateVerifyKey]: [InfimumDepositEvent.CoordinatorKeysChanged],
+    [InfimumExtrinsic.verifyOutcomeBatches]: [InfimumDepositEvent.PollProofVerified],
+    [InfimumExtrinsic.commitOutcomeFrom]: [InfimumDepositEvent.PollCommitmentUpdated, InfimumDepositEvent.PollOutcome, InfimumDepositEvent.PollProofBatchRejected],
+    [InfimumExtrinsic.setCoordinatorMetadata]: [InfimumDepositEvent.CoordinatorMetadataUpdated]
 };
 
 interface InfimumDepositEventData
@@ -108,11 +202,23 @@ interface InfimumDepositEventData
         who: string;
         publicKey: any;
         verifyKey: any;
+        processFingerprint: any;
+        tallyFingerprint: any;
     };
     [InfimumDepositEvent.CoordinatorKeysChanged]: {
         who: string;
         publicKey: any;
         verifyKey: any;
+        processFingerprint: any;
+        tallyFingerprint: any;
+    };
+    [InfimumDepositEvent.CoordinatorDepthKeysChanged]: {
+        who: string;
+        registrationDepth: string;
+        interactionDepth: string;
+        verifyKey: any;
+        processFingerprint: any;
+        tallyFingerprint: any;
     };
     [InfimumDepositEvent.ParticipantRegistered]: {
         pollId: string;
@@ -125,21 +231,31 @@ interface InfimumDepositEventData
         coordinator: string;
         startsAt: string;
         endsAt: string;
+        voteOptions: string[];
     };
     [InfimumDepositEvent.PollInteraction]: {
         pollId: string;
         count: string;
+        depth: string;
         publicKey: any;
         data: any;
     };
     [InfimumDepositEvent.PollCommitmentUpdated]: {
         pollId: string;
+        phase: 'Process' | 'Tally';
+        index: string;
+        value: any;
         commitment: any;
     };
     [InfimumDepositEvent.PollStateMerged]: {
         pollId: string;
+        phase: 'Registration' | 'Interaction';
         registrationRoot?: any;
         interactionRoot?: any;
+        registrationCount: string;
+        registrationDepth: string;
+        interactionCount: string;
+        interactionDepth: string;
     };
     [InfimumDepositEvent.PollOutcome]: {
         pollId: string;
@@ -148,6 +264,34 @@ interface InfimumDepositEventData
     [InfimumDepositEvent.PollNullified]: {
         pollId: string;
     };
+    [InfimumDepositEvent.PollProofVerified]: {
+        pollId: string;
+        commitment: any;
+    };
+    [InfimumDepositEvent.PollPruned]: {
+        pollId: string;
+    };
+    [InfimumDepositEvent.PollProofBatchRejected]: {
+        pollId: string;
+        accepted: string;
+        error: any;
+    };
+    [InfimumDepositEvent.CoordinatorLastPollResynced]: {
+        who: string;
+        lastPoll?: string;
+    };
+    [InfimumDepositEvent.PollStateTreeFilled]: {
+        pollId: string;
+        phase: 'Registration' | 'Interaction';
+    };
+    [InfimumDepositEvent.PollMetadataUpdated]: {
+        pollId: string;
+        metadataUri?: string;
+    };
+    [InfimumDepositEvent.CoordinatorMetadataUpdated]: {
+        who: string;
+        metadata?: string;
+    };
 }
 
 export const extrinsic = (
@@ -22,6 +22,7 @@ export interface VerifyingKeys
 {
     process: VerifyingKey;
     tally: VerifyingKey;
+    circuit_version: number;
 }
 
 export interface ProofData
@@ -31,6 +32,24 @@ export interface ProofData
     pi_c: Array<number>;
 }
 
+export interface PollConfigInput
+{
+    signup_period: number;
+    voting_period: number;
+    registration_depth: number;
+    interaction_depth: number;
+    process_subtree_depth: number;
+    tally_subtree_depth: number;
+    vote_option_tree_depth: number;
+    vote_options: Array<number>;
+    metadata?: Array<number>;
+    reject_empty_messages: boolean;
+    min_interactions: number;
+    max_interaction_depth?: number;
+    metadata_uri?: Array<number>;
+    allow_override?: boolean;
+}
+
 export interface PollOutcome
 {
     tallyResults: number[],
@@ -18,6 +18,12 @@ export const serializeProof = (relPath: string): ProofData =>
     return inf.serialize_proof(proof);
 };
 
+export const importSnarkjsProof = (relPath: string): ProofData =>
+{
+    const proof = readFileSync(path.join(__dirname, relPath)).toString();
+    return inf.import_snarkjs_proof(proof);
+};
+
 const readVerifyingKey = (path: string) =>
 {
     const {
@@ -45,11 +51,12 @@ const serializeVerifyingKey = (path: string): VerifyingKey =>
     return vkey;
 };
 
-export const loadVerifyingKeys = (path: string): VerifyingKeys =>
+export const loadVerifyingKeys = (path: string, circuitVersion: number = 0): VerifyingKeys =>
 {
     return {
         process: serializeVerifyingKey(`${path}/vk-process.json`),
-        tally: serializeVerifyingKey(`${path}/vk-tally.json`)
+        tally: serializeVerifyingKey(`${path}/vk-tally.json`),
+        circuit_version: circuitVersion
     };
 };
 
@@ -2,7 +2,7 @@ import { Keypair, PrivKey } from 'maci-domainobjs';
 import { ApiPromise } from '@polkadot/api';
 import { serializePublicKey, loadVerifyingKeys } from '../serialize';
 import { InfimumExtrinsic } from '../extrinsic';
-import { PollOutcome, ProofData, VerifyingKeys } from '../interface';
+import { PollConfigInput, PollOutcome, ProofData, VerifyingKeys } from '../interface';
 import { User } from './user';
 
 export class Coordinator extends User
@@ -65,6 +65,30 @@ export class Coordinator extends User
         return result.depositEvents.at(0)!.data;
     }
 
+    async createPollV2(config: PollConfigInput)
+    {
+        const result = await this.sendExtrinsic(InfimumExtrinsic.createPollV2, [config]);
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents.at(0)!.data;
+    }
+
+    async setPollMetadata(pollId: number, metadataUri?: Array<number>)
+    {
+        const result = await this.sendExtrinsic(InfimumExtrinsic.setPollMetadata, [pollId, metadataUri]);
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents.at(0)!.data;
+    }
+
+    async setCoordinatorMetadata(metadata?: Array<number>)
+    {
+        const result = await this.sendExtrinsic(InfimumExtrinsic.setCoordinatorMetadata, [metadata]);
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents.at(0)!.data;
+    }
+
     async mergePollState()
     {
         const result = await this.sendExtrinsic(InfimumExtrinsic.mergePollState, []);
@@ -90,6 +114,30 @@ export class Coordinator extends User
         return result.depositEvents;
     }
 
+    async verifyOutcomeBatches(
+        batches: Array<[ ProofData, Array<number> ]>,
+        outcome?: PollOutcome
+    )
+    {
+        const result = await this.sendExtrinsic(InfimumExtrinsic.verifyOutcomeBatches, [batches, outcome]);
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents;
+    }
+
+    async commitOutcomeFrom(
+        startIndex: number,
+        startCommitment: Array<number>,
+        batches: Array<[ ProofData, Array<number> ]>,
+        outcome?: PollOutcome
+    )
+    {
+        const result = await this.sendExtrinsic(InfimumExtrinsic.commitOutcomeFrom, [startIndex, startCommitment, batches, outcome]);
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents;
+    }
+
     async nullifyPoll()
     {
         const result = await this.sendExtrinsic(InfimumExtrinsic.nullifyPoll, []);
@@ -98,6 +146,22 @@ export class Coordinator extends User
         return result.depositEvents.at(0)!.data;
     }
 
+    async prunePoll(pollId: number)
+    {
+        const result = await this.sendExtrinsic(InfimumExtrinsic.prunePoll, [pollId]);
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents.at(0)!.data;
+    }
+
+    async resyncLastPoll()
+    {
+        const result = await this.sendExtrinsic(InfimumExtrinsic.resyncLastPoll, []);
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents;
+    }
+
     async rotateKeys(
         privateKey: string,
         verifyingKey: VerifyingKeys
@@ -118,4 +182,33 @@ export class Coordinator extends User
         if (result.error) throw new Error(result.error);
         return result.depositEvents.at(0)!.data;
     }
+
+    async rotateVerifyKey(verifyingKey: VerifyingKeys)
+    {
+        const result = await this.sendExtrinsic(InfimumExtrinsic.rotateVerifyKey, [verifyingKey]);
+
+        if (result.error) throw new Error(result.error);
+        this.verifyingKey = verifyingKey;
+
+        return result.depositEvents.at(0)!.data;
+    }
+
+    async registerDepthKeys(
+        registrationDepth: number,
+        interactionDepth: number,
+        verifyingKey: VerifyingKeys
+    )
+    {
+        const result = await this.sendExtrinsic(
+            InfimumExtrinsic.registerDepthKeys,
+            [
+                registrationDepth,
+                interactionDepth,
+                verifyingKey
+            ]
+        );
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents.at(0)!.data;
+    }
 }
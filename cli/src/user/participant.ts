@@ -47,4 +47,42 @@ export class Participant extends User
         if (result.error) throw new Error(result.error);
         return result.depositEvents.at(0)!.data;
     }
+
+    async submitVote(
+        pollId: number,
+        keypair: Keypair,
+        data: Array<Array<number>>,
+        voteOptionHint?: number
+    )
+    {
+        const result = await this.sendExtrinsic(
+            InfimumExtrinsic.submitVote,
+            [
+                pollId,
+                serializePublicKey(keypair),
+                data,
+                voteOptionHint
+            ]
+        );
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents.at(0)!.data;
+    }
+
+    async interactBatch(
+        pollId: number,
+        messages: Array<[ Keypair, Array<Array<number>> ]>
+    )
+    {
+        const result = await this.sendExtrinsic(
+            InfimumExtrinsic.interactBatch,
+            [
+                pollId,
+                messages.map(([ keypair, data ]) => [ serializePublicKey(keypair), data ])
+            ]
+        );
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents;
+    }
 }
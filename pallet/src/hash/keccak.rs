@@ -0,0 +1,19 @@
+use crate::hash::poseidon::HASH_LEN;
+
+/// Trait for hashing arbitrary byte inputs which are never opened within a circuit.
+pub trait BytesHasher
+{
+    /// Calculates the digest of the given input.
+    fn hash(input: &[u8]) -> [u8; HASH_LEN];
+}
+
+/// Keccak-256, used for auxiliary commitments which need not be circuit friendly.
+pub struct Keccak;
+
+impl BytesHasher for Keccak
+{
+    fn hash(input: &[u8]) -> [u8; HASH_LEN]
+    {
+        sp_io::hashing::keccak_256(input)
+    }
+}
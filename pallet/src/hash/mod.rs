@@ -1,4 +1,6 @@
+pub mod keccak;
 pub mod parameters;
 pub mod poseidon;
 
+pub use keccak::*;
 pub use poseidon::*;
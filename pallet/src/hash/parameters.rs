@@ -21,7 +21,7 @@ pub const ALPHA: u64 = 5;
 use core::convert::From;
 use sp_std::vec;
 
-use crate::hash::poseidon::{ PoseidonError, PoseidonParameters };
+use crate::hash::poseidon::{ PoseidonError, PoseidonParameters, MAX_X5_LEN };
 
 /// Returns Poseidon parameters for the BN254 curve with the following
 /// properties:
@@ -38,7 +38,7 @@ pub fn get_poseidon_parameters<F: PrimeField + From<ark_ff::BigInteger256>>(
     if t == 0_u8 {
         Err(PoseidonError::InvalidWidthCircom {
             width: t as usize,
-            max_limit: 13usize,
+            max_limit: MAX_X5_LEN,
         })
     } else if 2 == t {
         let ark = vec![
@@ -43075,7 +43075,7 @@ pub fn get_poseidon_parameters<F: PrimeField + From<ark_ff::BigInteger256>>(
     } else {
         return Err(PoseidonError::InvalidWidthCircom {
             width: t as usize,
-            max_limit: 13usize,
+            max_limit: MAX_X5_LEN,
         });
     }
 }
@@ -6,6 +6,8 @@ use sp_std::result::Result;
 use ark_bn254::Fr;
 use ark_ff::{BigInteger, PrimeField, Zero};
 
+use crate::hash::{BytesHasher, Keccak};
+
 pub const HASH_LEN: usize = 32;
 pub const MAX_X5_LEN: usize = 13;
 
@@ -28,6 +30,9 @@ pub enum PoseidonError
     U64ToU8,
     BytesToBigInt,
     InvalidWidthCircom { width: usize, max_limit: usize },
+    InvalidAlpha { alpha: u64, expected: u64 },
+    InvalidRoundsCircom { full_rounds: usize, partial_rounds: usize, width: usize },
+    InvalidDimensionsCircom { width: usize },
 }
 
 /// Parameters for the Poseidon hash algorithm.
@@ -68,6 +73,55 @@ impl<F: PrimeField> PoseidonParameters<F>
             alpha,
         }
     }
+
+    /// Returns the keccak digest of the round constants followed by the rows of
+    /// the MDS matrix, with each element serialized as big-endian bytes.
+    pub fn digest(&self) -> [u8; HASH_LEN]
+    {
+        let bytes: Vec<u8> = self.ark
+            .iter()
+            .chain(self.mds.iter().flatten())
+            .flat_map(|element| element.into_bigint().to_bytes_be())
+            .collect();
+
+        Keccak::hash(&bytes)
+    }
+
+    /// Checks that the S-box exponent, the number of rounds, and the dimensions of the round constants and MDS
+    /// matrix match those used by the circom circuits for this width, since a mismatch would silently produce
+    /// divergent hashes or index out of bounds whilst hashing.
+    pub fn validate_circom(&self) -> Result<(), PoseidonError>
+    {
+        use crate::hash::parameters::{ALPHA, FULL_ROUNDS, PARTIAL_ROUNDS};
+
+        if self.alpha != ALPHA {
+            return Err(PoseidonError::InvalidAlpha {
+                alpha: self.alpha,
+                expected: ALPHA,
+            });
+        }
+
+        let expected_partial_rounds = self.width
+            .checked_sub(2)
+            .and_then(|index| PARTIAL_ROUNDS.get(index));
+        if self.full_rounds != FULL_ROUNDS || expected_partial_rounds != Some(&self.partial_rounds) {
+            return Err(PoseidonError::InvalidRoundsCircom {
+                full_rounds: self.full_rounds,
+                partial_rounds: self.partial_rounds,
+                width: self.width,
+            });
+        }
+
+        let ark_len = (self.full_rounds + self.partial_rounds) * self.width;
+        if self.ark.len() != ark_len
+            || self.mds.len() != self.width
+            || self.mds.iter().any(|row| row.len() != self.width)
+        {
+            return Err(PoseidonError::InvalidDimensionsCircom { width: self.width });
+        }
+
+        Ok(())
+    }
 }
 
 /// Trait for hashing inputs that are prime field elements.
@@ -299,6 +353,18 @@ where
     Ok(element)
 }
 
+/// Interprets big-endian bytes as a field element, rejecting values which are not reduced modulo the field order.
+/// Bytes which should already hold a field element, e.g., the roots and commitments produced by the pallet, are
+/// lifted with this rather than `Fr::from_be_bytes_mod_order`, such that corrupted values are surfaced rather than
+/// silently folded onto another element.
+pub fn hashbytes_to_fr_checked(bytes: &[u8; HASH_LEN]) -> Result<Fr, PoseidonError>
+{
+    let element = Fr::from_be_bytes_mod_order(bytes);
+    if element.into_bigint().to_bytes_be().as_slice() != bytes.as_slice() { Err(PoseidonError::InputLargerThanModulus)? }
+
+    Ok(element)
+}
+
 impl<F: PrimeField> Poseidon<F>
 {
     pub fn new_circom(nr_inputs: usize) -> Result<Poseidon<Fr>, PoseidonError>
@@ -311,17 +377,20 @@ impl<F: PrimeField> Poseidon<F>
         domain_tag: Fr,
     ) -> Result<Poseidon<Fr>, PoseidonError>
     {
-        let width = nr_inputs + 1;
-        if width > MAX_X5_LEN {
+        // Reject before computing the width so that oversized inputs cannot overflow.
+        if nr_inputs >= MAX_X5_LEN {
             return Err(PoseidonError::InvalidWidthCircom {
-                width,
+                width: nr_inputs.saturating_add(1),
                 max_limit: MAX_X5_LEN,
             });
         }
+        let width = nr_inputs + 1;
 
         let params = crate::hash::parameters::get_poseidon_parameters::<Fr>(
             width.try_into().map_err(|_| PoseidonError::U64ToU8)?,
         )?;
+        params.validate_circom()?;
+
         Ok(Poseidon::<Fr>::with_domain_tag(params, domain_tag))
     }
 }
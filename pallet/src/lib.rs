@@ -2,7 +2,6 @@
 
 pub use pallet::*;
 use sp_std::vec;
-use sp_runtime::traits::SaturatedConversion;
 
 use ark_bn254::{
     Bn254,
@@ -10,6 +9,7 @@ use ark_bn254::{
     G1Affine, 
     G2Affine
 };
+use ark_ff::{PrimeField, BigInteger};
 use ark_serialize::{CanonicalDeserialize};
 use ark_crypto_primitives::snark::SNARK;
 use ark_groth16::{
@@ -20,8 +20,13 @@ use ark_groth16::{
 
 pub mod hash;
 pub mod poll;
+pub mod prelude;
+pub mod runtime_api;
+pub mod weights;
 
 pub use poll::*;
+use hash::{BytesHasher, Keccak};
+use weights::WeightInfo;
 
 #[cfg(test)]
 mod mock;
@@ -38,8 +43,15 @@ pub mod pallet
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
+	use frame_support::dispatch::WithPostDispatchInfo;
+	use frame_support::log;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	/// Version 1 changed the encoding of the coordinator and poll entries prior to launch, without a translation of the
+	/// version 0 entries.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// The target of the pallet log messages.
+	const LOG_TARGET: &str = "runtime::infimum";
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -64,9 +76,55 @@ pub mod pallet
 		#[pallet::constant]
 		type MaxPollRegistrations: Get<u32>;
 
-		/// The maximum allowable number of poll interactions.
+		/// The maximum allowable number of poll interactions. Polls whose interaction tree capacity exceeds this are rejected.
 		#[pallet::constant]
 		type MaxPollInteractions: Get<u32>;
+
+		/// The maximum number of items which may be processed by a single call, such as the messages of a batched interaction.
+		#[pallet::constant]
+		type MaxIterationDepth: Get<u32>;
+
+		/// Whether participants are prevented from registering in a poll under the public key of its coordinator.
+		#[pallet::constant]
+		type RejectCoordinatorKeyAsParticipant: Get<bool>;
+
+		/// Whether interactions are rejected if their ephemeral public key was used to register in the same poll. NB this
+		/// conflicts with `RequireRegisteredInteractions`, since together they reject every interaction, and hence at most
+		/// one of the two may be enabled.
+		#[pallet::constant]
+		type RejectEphemeralKeyReuse: Get<bool>;
+
+		/// Whether interactions are rejected unless their public key was used to register in the same poll. Since
+		/// participants may rotate their key with any message, interactions under a rotated key are also rejected. NB this
+		/// conflicts with `RejectEphemeralKeyReuse`, and hence at most one of the two may be enabled.
+		#[pallet::constant]
+		type RequireRegisteredInteractions: Get<bool>;
+
+		/// The minimum number of blocks for which the registration period of a poll is active, or zero.
+		#[pallet::constant]
+		type MinSignupPeriod: Get<BlockNumber>;
+
+		/// The minimum number of blocks for which the voting period of a poll is active, or zero.
+		#[pallet::constant]
+		type MinVotingPeriod: Get<BlockNumber>;
+
+		/// The minimum number of blocks between the creation of successive polls by a coordinator, or zero.
+		#[pallet::constant]
+		type PollCreationCooldown: Get<BlockNumber>;
+
+		/// The maximum length of any metadata supplied by a coordinator, e.g., the description of a poll or its metadata uri.
+		#[pallet::constant]
+		type MaxMetadataLen: Get<u32>;
+
+		/// The maximum number of tree depths for which a coordinator may register depth specific verification keys.
+		#[pallet::constant]
+		type MaxDepthKeys: Get<u32>;
+
+		/// The origin which may register as a coordinator, resolving to the account of the coordinator.
+		type CoordinatorOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// The weights of proof verification and state tree merging. NB the `()` weights are unbenchmarked placeholders.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::event]
@@ -80,7 +138,11 @@ pub mod pallet
 			/// The public key of the coordinator.
 			public_key: PublicKey,
 			/// The verifying keys of the coordinator.
-			verify_key: VerifyingKeys
+			verify_key: VerifyingKeys,
+			/// The fingerprint of the message processing verifying key.
+			process_fingerprint: HashBytes,
+			/// The fingerprint of the tally verifying key.
+			tally_fingerprint: HashBytes
 		},
 
 		/// A coordinator rotated one of their keys.
@@ -90,7 +152,27 @@ pub mod pallet
 			/// The new public key.
 			public_key: PublicKey,
 			/// The new verifying keys.
-			verify_key: VerifyingKeys
+			verify_key: VerifyingKeys,
+			/// The fingerprint of the new message processing verifying key.
+			process_fingerprint: HashBytes,
+			/// The fingerprint of the new tally verifying key.
+			tally_fingerprint: HashBytes
+		},
+
+		/// A coordinator registered verifying keys for polls of specific tree depths.
+		CoordinatorDepthKeysChanged {
+			/// The coordinator.
+			who: T::AccountId,
+			/// The registration tree depth the keys apply to.
+			registration_depth: u8,
+			/// The interaction tree depth the keys apply to.
+			interaction_depth: u8,
+			/// The new verifying keys.
+			verify_key: VerifyingKeys,
+			/// The fingerprint of the new message processing verifying key.
+			process_fingerprint: HashBytes,
+			/// The fingerprint of the new tally verifying key.
+			tally_fingerprint: HashBytes
 		},
 
 		/// A participant registered to vote in a poll.
@@ -113,8 +195,11 @@ pub mod pallet
 			coordinator: T::AccountId,
 			/// The block number the poll signup period ends and voting commences.
 			starts_at: BlockNumber,
-			/// The block number the voting period commences.
-			ends_at: BlockNumber
+			/// The first block number following the voting period, from which the poll is over and its state may be merged.
+			/// NB this is one greater than the voting period end which is supplied to the circuits, see `voting_period_end`.
+			ends_at: BlockNumber,
+			/// The options which may be voted for.
+			vote_options: VoteOptions<T>
 		},
 
 		/// Poll was interacted with.
@@ -123,6 +208,8 @@ pub mod pallet
 			poll_id: PollId,
 			/// The current interaction count.
 			count: u32,
+			/// The current depth of the interaction tree, i.e. the height of its largest filled subtree.
+			depth: u8,
 			/// Ephemeral public key used to encrypt the message.
 			public_key: PublicKey,
 			/// Interaction data.
@@ -133,6 +220,12 @@ pub mod pallet
 		PollCommitmentUpdated {
 			/// The poll index.
 			poll_id: PollId,
+			/// The commitment chain which advanced.
+			phase: CommitmentPhase,
+			/// The number of proofs verified in the advanced commitment chain.
+			index: CommitmentIndex,
+			/// The latest commitment value of the advanced commitment chain.
+			value: CommitmentData,
 			/// The new commitment value.
 			commitment: Commitment
 		},
@@ -141,10 +234,20 @@ pub mod pallet
 		PollStateMerged {
 			/// The poll index.
 			poll_id: PollId,
-			/// The poll registrations tree root.
+			/// The state tree which was merged.
+			phase: MergePhase,
+			/// The poll registrations tree root, if merged.
 			registration_root: Option<HashBytes>,
-			/// The poll interactions tree root.
+			/// The poll interactions tree root, if merged.
 			interaction_root: Option<HashBytes>,
+			/// The number of registrations.
+			registration_count: u32,
+			/// The depth of the non-zero leaves of the registration tree, as supplied to the circuits.
+			registration_depth: u8,
+			/// The number of interactions.
+			interaction_count: u32,
+			/// The depth of the interaction tree, including any growth beyond its initial depth.
+			interaction_depth: u8
 		},
 
 		/// Poll result was verified.
@@ -159,6 +262,68 @@ pub mod pallet
 		PollNullified {
 			/// The poll index.
 			poll_id: PollId
+		},
+
+		/// Poll creation was enabled or disabled.
+		PollCreationToggled {
+			/// Whether new polls may be created.
+			enabled: bool
+		},
+
+		/// A proof batch was verified without advancing the poll state.
+		PollProofVerified {
+			/// The poll index.
+			poll_id: PollId,
+			/// The commitment which the verified batch would advance the poll to.
+			commitment: Commitment
+		},
+
+		/// The working state of a finalized poll was pruned.
+		PollPruned {
+			/// The poll index.
+			poll_id: PollId
+		},
+
+		/// A proof batch was rejected after one or more preceding batches were verified and committed.
+		PollProofBatchRejected {
+			/// The poll index.
+			poll_id: PollId,
+			/// The number of batches which were verified and committed.
+			accepted: u32,
+			/// The reason the batch was rejected.
+			error: DispatchError
+		},
+
+		/// The most recent poll of a coordinator was recomputed from the polls they manage.
+		CoordinatorLastPollResynced {
+			/// The coordinator.
+			who: T::AccountId,
+			/// The most recent poll of the coordinator.
+			last_poll: Option<PollId>
+		},
+
+		/// A poll state tree was filled, and accepts no further leaves.
+		PollStateTreeFilled {
+			/// The poll index.
+			poll_id: PollId,
+			/// The state tree which was filled.
+			phase: MergePhase
+		},
+
+		/// The metadata uri of a poll was updated by its coordinator.
+		PollMetadataUpdated {
+			/// The poll index.
+			poll_id: PollId,
+			/// The new metadata uri, if any.
+			metadata_uri: Option<vec::Vec<u8>>
+		},
+
+		/// The metadata of a coordinator was updated.
+		CoordinatorMetadataUpdated {
+			/// The coordinator.
+			who: T::AccountId,
+			/// The new metadata, if any.
+			metadata: Option<vec::Vec<u8>>
 		}
 	}
 
@@ -207,26 +372,101 @@ pub mod pallet
 		/// Poll outcome was previously committed and verified.
 		PollOutcomeAlreadyDetermined,
 
+		/// Poll outcome is inconsistent with the final tally commitment.
+		PollOutcomeInvalid,
+
 		/// Poll state trees have not yet been merged.
 		PollStateNotMerged,
 
-		/// Poll state tree merge operation failed.
+		/// Poll state tree merge operation failed. The `reason` is a [`MerkleTreeError`] code.
 		PollMergeFailed { reason: u8 },
 
-		/// Poll registration failed.
+		/// Poll registration failed. The `reason` is a [`MerkleTreeError`] code.
 		PollRegistrationFailed { reason: u8 },
 
-		/// Poll interaction failed.
+		/// Poll interaction failed. The `reason` is a [`MerkleTreeError`] code.
 		PollInteractionFailed { reason: u8 },
 
 		/// The key(s) provided are malformed.
 		MalformedKeys,
 
-		/// A proof was rejected.
-		MalformedProof,
+		/// A proof was rejected. The `reason` is a [`VerifyError`] code.
+		MalformedProof { reason: u8 },
 
 		/// The extrinsic arguments are insufficient.
-		MalformedInput
+		MalformedInput,
+
+		/// The vote option hint does not index into the poll vote options.
+		VoteOptionOutOfRange,
+
+		/// The coordinator has not registered verifying keys for the configured poll tree depths.
+		NoVerifyKeyForDepth,
+
+		/// The interaction data consists entirely of zeroes.
+		EmptyInteractionData,
+
+		/// A proof batch was submitted after every expected proof had been verified.
+		ExcessProofBatch,
+
+		/// The poll recorded fewer interactions than its configured minimum.
+		InsufficientInteractions,
+
+		/// The poll has been nullified and may no longer be mutated.
+		PollInactive,
+
+		/// The verifying keys were generated for a different circuit revision than the poll was created with.
+		CircuitVersionMismatch,
+
+		/// The public key is not a point on the Baby Jubjub curve.
+		InvalidPublicKey,
+
+		/// The creation of new polls has been disabled.
+		PollCreationDisabled,
+
+		/// The signer is not the coordinator of the poll.
+		NotPollCoordinator,
+
+		/// The poll ended without a single interaction, and may only be nullified.
+		NoInteractionsToTally,
+
+		/// Participant tried to register under the public key of the poll coordinator.
+		CoordinatorKeyAsParticipant,
+
+		/// Coordinator supplied metadata which exceeds the maximum length.
+		MetadataTooLong,
+
+		/// Interaction was submitted under a public key which was used to register in the poll.
+		EphemeralKeyReused,
+
+		/// The current block number, or a poll deadline derived from it, cannot be represented as a `BlockNumber`.
+		BlockNumberOutOfRange,
+
+		/// Interaction was submitted under a public key which was not used to register in the poll.
+		UnregisteredInteraction,
+
+		/// Coordinator submitted tally proof batches which do not continue from the expected tally commitment, either as declared
+		/// by the caller or as implied by a tally proof which failed verification. The tally chain is seeded from the final
+		/// commitment of the process chain.
+		TallyCommitmentMismatch,
+
+		/// Coordinator tried to nullify a poll which has already been nullified.
+		PollAlreadyNullified,
+
+		/// Coordinator submitted proof batches which do not continue from the stored commitment index, or from the stored
+		/// commitment of the process chain.
+		CommitmentIndexMismatch,
+
+		/// Signer supplied a public key whose coordinates are both zero.
+		ZeroPublicKey,
+
+		/// Coordinator tried to create a poll with more vote options than the runtime permits.
+		TooManyVoteOptions,
+
+		/// Coordinator tried to create a poll before the cooldown following their previous poll elapsed.
+		PollCreationCooldown,
+
+		/// Coordinator tried to register verification keys for more tree depths than the runtime permits.
+		TooManyDepthKeys
 	}
 
 	/// Map of ids to polls.
@@ -239,6 +479,21 @@ pub mod pallet
 		Poll<T>
 	>;
 
+	/// Map of ids to the immutable records of finalized polls, which are retained if the poll working state is pruned.
+	#[pallet::storage]
+	#[pallet::getter(fn finalized_poll)]
+	pub type FinalizedPolls<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		PollId,
+		FinalizedPoll<T>
+	>;
+
+	/// The index of the next poll to be created, equivalently the total number of polls ever created.
+	#[pallet::storage]
+	#[pallet::getter(fn total_polls)]
+	pub type NextPollId<T: Config> = StorageValue<_, PollId, ValueQuery>;
+
 	/// Map of coordinators to their keys.
 	#[pallet::storage]
 	#[pallet::getter(fn coordinators)]
@@ -246,7 +501,7 @@ pub mod pallet
 		_, 
 		Blake2_128Concat, 
 		T::AccountId,
-		Coordinator
+		Coordinator<T>
 	>;
 
 	/// Map of coordinators to the poll Ids they manage.
@@ -260,6 +515,87 @@ pub mod pallet
 		ValueQuery
 	>;
 
+	/// Double map of polls and the accounts which have registered in them.
+	#[pallet::storage]
+	pub type Registrants<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PollId,
+		Blake2_128Concat,
+		T::AccountId,
+		()
+	>;
+
+	/// Double map of polls and the public keys which were registered in them. Only recorded whilst either
+	/// `RejectEphemeralKeyReuse` or `RequireRegisteredInteractions` is enabled.
+	#[pallet::storage]
+	pub type RegisteredKeys<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PollId,
+		Blake2_128Concat,
+		PublicKey,
+		()
+	>;
+
+	/// Double map of polls and the number of interactions submitted under each public key. Since participants 
+	/// may rotate their ephemeral key with any message, this counts interactions per key rather than per participant.
+	#[pallet::storage]
+	#[pallet::getter(fn interaction_count)]
+	pub type InteractionCounts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PollId,
+		Blake2_128Concat,
+		PublicKey,
+		u32,
+		ValueQuery
+	>;
+
+	#[pallet::type_value]
+	pub fn DefaultPollCreationEnabled() -> bool { true }
+
+	/// Whether new polls may be created. Extant polls are unaffected.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_creation_enabled)]
+	pub type PollCreationEnabled<T: Config> = StorageValue<_, bool, ValueQuery, DefaultPollCreationEnabled>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T>
+	{
+		fn integrity_test()
+		{
+			// An interaction key must be registered in the poll, and must not be, so no interaction would be accepted.
+			assert!(
+				!(T::RejectEphemeralKeyReuse::get() && T::RequireRegisteredInteractions::get()),
+				"`RejectEphemeralKeyReuse` and `RequireRegisteredInteractions` may not both be enabled"
+			);
+		}
+
+		/// Clears the coordinator and poll entries encoded under version 0, which may not be decoded under version 1.
+		fn on_runtime_upgrade() -> Weight
+		{
+			if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION { return T::DbWeight::get().reads(1); }
+
+			let removed = [
+				Coordinators::<T>::clear(u32::MAX, None),
+				CoordinatorPollIds::<T>::clear(u32::MAX, None),
+				Polls::<T>::clear(u32::MAX, None),
+				FinalizedPolls::<T>::clear(u32::MAX, None),
+				Registrants::<T>::clear(u32::MAX, None),
+				RegisteredKeys::<T>::clear(u32::MAX, None),
+				InteractionCounts::<T>::clear(u32::MAX, None)
+			];
+			let reads: u64 = removed.iter().map(|result| u64::from(result.loops)).sum();
+			let writes: u64 = removed.iter().map(|result| u64::from(result.unique)).sum();
+
+			STORAGE_VERSION.put::<Pallet<T>>();
+			log::info!(target: LOG_TARGET, "cleared {} entries encoded under storage version 0", writes);
+
+			T::DbWeight::get().reads_writes(reads.saturating_add(1), writes.saturating_add(1))
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> 
 	{
@@ -277,13 +613,16 @@ pub mod pallet
 			verify_key: VerifyingKeys
 		) -> DispatchResult
 		{
-			// Check that the extrinsic was signed and get the signer.
-			let sender = ensure_signed(origin)?;
+			// Check that the origin is permitted to coordinate polls and get the signer.
+			let sender = T::CoordinatorOrigin::ensure_origin(origin)?;
 
 			// Ensure the verification keys can be serialized as affine points.
 			ensure!(serialize_vkey(verify_key.process.clone()).is_some(), Error::<T>::MalformedKeys);
 			ensure!(serialize_vkey(verify_key.tally.clone()).is_some(), Error::<T>::MalformedKeys);
 
+			// Ensure that the public key has been initialized.
+			ensure!(!public_key.is_zero(), Error::<T>::ZeroPublicKey);
+
 			// A coordinator may only be registered once.
 			ensure!(
 				!Coordinators::<T>::contains_key(&sender), 
@@ -294,13 +633,18 @@ pub mod pallet
 			Coordinators::<T>::insert(&sender, Coordinator {
 				last_poll: None,
 				public_key,
-				verify_key: verify_key.clone()
+				verify_key: verify_key.clone(),
+				depth_keys: BoundedVec::new(),
+				last_poll_created_at: None,
+				metadata: None
 			});
 
 			// Emit a registration event.
 			Self::deposit_event(Event::CoordinatorRegistered {
 				who: sender,
 				public_key,
+				process_fingerprint: verify_key.process.fingerprint(),
+				tally_fingerprint: verify_key.tally.fingerprint(),
 				verify_key
 			});
 
@@ -329,6 +673,9 @@ pub mod pallet
 			ensure!(serialize_vkey(verify_key.process.clone()).is_some(), Error::<T>::MalformedKeys);
 			ensure!(serialize_vkey(verify_key.tally.clone()).is_some(), Error::<T>::MalformedKeys);
 
+			// Ensure that the public key has been initialized.
+			ensure!(!public_key.is_zero(), Error::<T>::ZeroPublicKey);
+
 			// Check if origin is registered as a coordinator.
 			let Some(mut coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
 
@@ -354,6 +701,8 @@ pub mod pallet
 			Self::deposit_event(Event::CoordinatorKeysChanged {
 				who: sender,
 				public_key,
+				process_fingerprint: verify_key.process.fingerprint(),
+				tally_fingerprint: verify_key.tally.fingerprint(),
 				verify_key
 			});
 
@@ -367,6 +716,9 @@ pub mod pallet
 		/// - `max_registrations`: The maximum number of participants permitted.
 		/// - `vote_options`: The possible outcomes of the poll.
 		///
+		/// The optional poll parameters take their default values, and may only be supplied by name via `create_poll_v2`.
+		/// Rejected if poll creation has been disabled, or if either period is shorter than the runtime minimum.
+		///
 		/// Emits `PollCreated`.
 		#[pallet::call_index(2)]
 		#[pallet::weight(T::DbWeight::get().reads_writes(4, 3))]
@@ -385,170 +737,78 @@ pub mod pallet
 			// Check that the extrinsic was signed and get the signer.
 			let sender = ensure_signed(origin)?;
 
-			// Validate config parameters.
-			let created_at = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
-			let max_registrations = 2_u32.pow(registration_depth.into());
-			ensure!(
-				max_registrations <= T::MaxPollRegistrations::get(),
-				Error::<T>::PollConfigInvalid
-			);
-			let max_interactions = 5_u32.pow(interaction_depth.into());
-			ensure!(
-				max_interactions <= T::MaxPollInteractions::get(),
-				Error::<T>::PollConfigInvalid
-			);
-
-			ensure!(vote_options.len() > 1, Error::<T>::PollConfigInvalid);
-			let vote_options: VoteOptions<T> = vote_options
-				.try_into()
-				.map_err(|_| Error::<T>::PollConfigInvalid)?;
-
-			// Check that sender is registered as a coordinator.
-			let Some(mut coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
-
-			let coord_poll_ids = Self::poll_ids(&sender);
-
-			// A coordinator may have at most `MaxCoordinatorPolls` polls, skipped if zero.
-			let max_polls = T::MaxCoordinatorPolls::get() as usize;
-			ensure!(
-				coord_poll_ids.len() < max_polls,
-				Error::<T>::CoordinatorPollLimitReached
-			);
-
-			// A coordinator may only have a single active poll at a given time.
-			if let Some(index) = coord_poll_ids.last()
-			{
-				if let Some(poll) = Polls::<T>::get(index)
-				{
-					// Reject if last created poll is on-going, or has yet to be processed.
-					ensure!(
-						poll.is_over() && poll.is_fulfilled(),
-						Error::<T>::PollCurrentlyActive 
-					);
-				}
-			}
-
-			// Insert the poll into storage.
-			let index = Polls::<T>::count();
-			Polls::<T>::insert(&index, Poll {
-				index,
-				created_at,
-				coordinator: sender.clone(),
-				state: PollState::new(
-					registration_depth,
-					interaction_depth
-				),
-				config: PollConfiguration {
-					signup_period,
-					voting_period,
-					max_registrations,
-					max_interactions,
-					process_subtree_depth,
-					tally_subtree_depth,
-					vote_option_tree_depth,
-					vote_options
-				}
-			});
-
-			coordinator.last_poll = Some(index);
-			Coordinators::<T>::insert(&sender, coordinator);
-			CoordinatorPollIds::<T>::append(&sender, index);
-
-			// Emit the creation event.
-			let starts_at = created_at + signup_period;
-			let ends_at = starts_at + voting_period + 1;
-			Self::deposit_event(Event::PollCreated { 
-				coordinator: sender,
-				poll_id: index,
-				starts_at,
-				ends_at
-			});
-
-			Ok(())
+			Self::do_create_poll(sender, PollConfigInput {
+				signup_period,
+				voting_period,
+				registration_depth,
+				interaction_depth,
+				process_subtree_depth,
+				tally_subtree_depth,
+				vote_option_tree_depth,
+				vote_options,
+				metadata: None,
+				reject_empty_messages: false,
+				min_interactions: 0,
+				max_interaction_depth: None,
+				metadata_uri: None,
+				allow_override: None
+			})
 		}
 
 		/// Compute the roots of the current poll state trees. This operation must be performed prior to commiting the poll outcome. 
-		/// Registration tree may be merged as long as the registration period has elapsed, and the interaction tree may be merged 
+		/// Registration tree may be merged once a block has elapsed after the registration period, and the interaction tree may be merged 
 		/// as long as the voting period has elapsed. NB Coordinator's are required to call this extrinsic twice: once to merge the 
 		/// registration state tree, and once to merge the interaction state tree.
 		///
-		/// Emits `PollStateMerged`.
+		/// Emits `PollStateMerged`. Unused weight is refunded, including if the merge is rejected.
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1))] 
+		#[pallet::weight(Pallet::<T>::merge_poll_state_weight(PollState::max_merge_cost()))]
 		pub fn merge_poll_state(
 			origin: OriginFor<T>
-		) -> DispatchResult
+		) -> DispatchResultWithPostInfo
 		{
 			// Check that the extrinsic was signed and get the signer.
 			let sender = ensure_signed(origin)?;
-			
-			// Get the coordinators most recent poll.
-			let Some(coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
-			let Some(poll_id) = coordinator.last_poll else { Err(<Error::<T>>::PollDoesNotExist)? };
-			let Some(poll) = Polls::<T>::get(poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
-
-			// Check that the poll is not currently in the registration period.
-			ensure!(
-				!poll.is_registration_period(),
-				Error::<T>::PollRegistrationInProgress
-			);
-
-			if poll.state.registrations.root.is_none()
-			{
-				// Ensure that there was at least one registration.
-				ensure!(
-					poll.state.registrations.count > 0,
-					Error::<T>::PollDataEmpty
-				);
 
-				// Compute the root of the registration tree and save it.
-				let poll = poll
-					.merge_registrations()
-					.map_err(|error| Error::<T>::PollMergeFailed { reason: error.into() })?;
+			// Failures prior to merging either tree are only charged for the storage reads.
+			let (poll_id, poll, phase) = Self::prepare_merge_poll_state(&sender)
+				.map_err(|error| error.with_weight(Self::merge_poll_state_weight(0)))?;
 
-				Polls::<T>::insert(&poll_id, poll.clone());
-
-				// Emit the hash event.
-				Self::deposit_event(Event::PollStateMerged {
-					poll_id,
-					registration_root: poll.state.registrations.root,
-					interaction_root: None
-				});
-			}
-
-			else if poll.state.interactions.root.is_none()
+			// Compute the root of the tree and save it.
+			let (hashes, result) = match phase
 			{
-				// Check that the poll is not currenltly in the voting period.
-				ensure!(
-					poll.is_over(),
-					Error::<T>::PollVotingInProgress
-				);
-
-				// Ensure that there was at least one interaction.
-				ensure!(
-					poll.state.interactions.count > 0,
-					Error::<T>::PollDataEmpty
-				);
-
-				// Compute the root of the interaction tree and save it.
-				let poll = poll
-					.merge_interactions()
-					.map_err(|error| Error::<T>::PollMergeFailed { reason: error.into() })?;
-
-				Polls::<T>::insert(&poll_id, poll.clone());
-
-				// Emit the hash event.
-				Self::deposit_event(Event::PollStateMerged {
-					poll_id,
-					registration_root: None,
-					interaction_root: poll.state.interactions.root
-				});
-			}
-
-			// Poll data has already been merged.
-			else { Err(<Error::<T>>::PollDataEmpty)? }
+				MergePhase::Registration => (
+					poll.state.registrations.pending_merge_cost(false),
+					poll.merge_registrations()
+				),
+				MergePhase::Interaction => (
+					poll.state.interactions.pending_merge_cost(true),
+					poll.merge_interactions()
+				)
+			};
+			let hashes = hashes.min(PollState::max_merge_cost());
+
+			// A failed merge is charged for the hashes it may have computed.
+			let poll = result
+				.map_err(|error| Error::<T>::PollMergeFailed { reason: error.into() }
+					.with_weight(Self::merge_poll_state_weight(hashes)))?;
+
+			Polls::<T>::insert(&poll_id, poll.clone());
+
+			// Emit the hash event.
+			Self::deposit_event(Event::PollStateMerged {
+				poll_id,
+				phase,
+				registration_root: poll.state.registrations.root,
+				interaction_root: poll.state.interactions.root,
+				registration_count: poll.state.registrations.count,
+				registration_depth: poll.state.registrations.depth,
+				interaction_count: poll.state.interactions.count,
+				interaction_depth: poll.state.interactions.full_depth
+			});
 
-			Ok(())
+			// Refund the weight of the hashes which were not computed.
+			Ok(Some(Self::merge_poll_state_weight(hashes)).into())
 		}
 
 		/// Permits the coordinator to commit, in batches, proofs that all of the valid participant registrations and poll interactions 
@@ -560,82 +820,31 @@ pub mod pallet
 		/// - `batches`: The ordered proofs alongside 
 		/// - `outcome`: The index of the option voted for (from the `VoteOptions` vec in the poll configuration). This parameter
 		///				 should only be included only with the last batch, or in a separate call after the final batch has been verified.
+		///				 Rejected with `PollOutcomeInvalid` if the final tally commitment does not match the outcome.
 		/// 
-		/// Emits `PollOutcome` once the outcome been verified, and `PollCommitmentUpdated` to reflect the updated commitment.
+		/// Emits `PollOutcome` once the outcome been verified, and `PollCommitmentUpdated` for each commitment chain advanced.
+		/// If a batch is rejected after one or more batches were verified, the verified batches are committed,
+		/// the remaining batches and the outcome are discarded, and `PollProofBatchRejected` is emitted.
+		/// Unused weight is refunded if a batch is rejected.
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
+		#[pallet::weight(Pallet::<T>::commit_outcome_weight(batches.len() as u32))]
 		pub fn commit_outcome(
 			origin: OriginFor<T>,
 			batches: ProofBatches,
 			outcome: Option<PollOutcome>
-		) -> DispatchResult
+		) -> DispatchResultWithPostInfo
 		{
 			// Check that the extrinsic was signed and get the signer.
 			let sender = ensure_signed(origin)?;
 
-			// Get the coordinators most recent poll.
-			let Some(coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
-			let Some(poll_id) = coordinator.last_poll else { Err(<Error::<T>>::PollDoesNotExist)? };
-			let Some(mut poll) = Polls::<T>::get(poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
-
-			// Check that the state trees have been merged 
-			ensure!(poll.is_merged(), Error::<T>::PollStateNotMerged);
-
-			// Check that the outcome has not already been committed.
-			ensure!(!poll.is_fulfilled(), Error::<T>::PollOutcomeAlreadyDetermined);
-
-			// Ensure at least one of the inputs have been provided.
-			ensure!(batches.len() > 0 || outcome.is_some(), Error::<T>::MalformedInput);
-
-			// Verify each batch of proofs in order.
-			for (proof, new_commitment) in batches.iter()
-			{
-				let Some((
-					verify_key,
-					public_inputs,
-					commitment
-				)) = poll.clone().prepare_public_inputs(
-					coordinator.clone(),
-					*new_commitment
-				) else { Err(<Error::<T>>::MalformedProof)? };
-
-				ensure!(
-					verify_proof(verify_key, public_inputs, proof.clone()),
-					Error::<T>::MalformedProof
-				);
-
-				poll.state.commitment = commitment;
-			}
-
-			// Publish the commitment from the final batch.
-			if batches.len() > 0
-			{
-				Self::deposit_event(Event::PollCommitmentUpdated {
-					poll_id,
-					commitment: poll.clone().state.commitment
-				})
-			}
-
-			// Once the final proof batch is verified, verify that the outcome matches the final commitment.
-			if let Some(outcome_index) = poll.clone().verify_outcome(outcome)
-			{
-				poll.state.outcome = Some(outcome_index);
-
-				Self::deposit_event(Event::PollOutcome { 
-					poll_id,
-					outcome_index
-				});
-			}
-
-			// Update the poll state.
-			Polls::<T>::insert(poll_id, poll);
-
-			Ok(())
+			Self::do_commit_outcome(sender, batches, outcome, None, false)
 		}
 
-		/// Permits the coordinator to nullify a poll which expired without recording a single interaction.
+		/// Permits the coordinator to nullify a poll which expired without recording a single interaction, or fewer 
+		/// interactions than the poll minimum. The working state of the state trees is discarded, retaining their counts
+		/// and any roots.
 		///
-		/// Calls to this extrinsic are rejected if the poll has not ended, or there was at least one interaction.
+		/// Calls to this extrinsic are rejected if the poll has not ended, or there were sufficiently many interactions.
 		/// 
 		/// Emits `PollNullified`.
 		#[pallet::call_index(5)]
@@ -652,19 +861,22 @@ pub mod pallet
 			let Some(poll_id) = coordinator.last_poll else { Err(<Error::<T>>::PollDoesNotExist)? };
 			let Some(poll) = Polls::<T>::get(poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
 
+			// A poll may only be nullified once.
+			ensure!(!poll.is_nullified(), Error::<T>::PollAlreadyNullified);
+
 			ensure!(
 				(!poll.is_registration_period() && poll.state.registrations.count == 0) || 
-				(poll.is_over() && poll.state.interactions.count == 0),
+				(poll.is_over() && (poll.state.interactions.count == 0 || poll.state.interactions.count < poll.config.min_interactions)),
 				Error::<T>::PollCurrentlyActive
 			);
 
+			// Mark the poll as dead.
+			Polls::<T>::insert(poll_id, poll.nullify());
+
 			Self::deposit_event(Event::PollNullified {
 				poll_id
 			});
 
-			// Mark the poll as dead.
-			Polls::<T>::insert(poll_id, poll.nullify());
-
 			Ok(())
 		}
 
@@ -675,7 +887,7 @@ pub mod pallet
 		///
 		/// Emits `ParticipantRegistered`.
 		#[pallet::call_index(6)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 3))]
 		pub fn register_as_participant(
 			origin: OriginFor<T>,
 			poll_id: PollId,
@@ -683,17 +895,34 @@ pub mod pallet
 		) -> DispatchResult
 		{
 			// Check that the extrinsic was signed and get the signer.
-			ensure_signed(origin)?;
+			let sender = ensure_signed(origin)?;
+
+			// Ensure that the public key is a valid curve point.
+			ensure!(!public_key.is_zero(), Error::<T>::ZeroPublicKey);
+			ensure!(public_key.is_on_curve(), Error::<T>::InvalidPublicKey);
 
 			// Ensure that the poll exists and get it.
 			let Some(poll) = Polls::<T>::get(&poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
 
+			// Ensure that the poll has not been nullified.
+			ensure!(!poll.is_dead(), Error::<T>::PollInactive);
+
 			// Check that the poll is still in the signup period.
 			ensure!(
 				poll.is_registration_period(),
 				Error::<T>::PollRegistrationHasEnded
 			);
 
+			// Optionally reject registrations under the public key of the poll coordinator.
+			if T::RejectCoordinatorKeyAsParticipant::get()
+			{
+				let coordinator_key = Coordinators::<T>::get(&poll.coordinator).map(|coordinator| coordinator.public_key);
+				ensure!(
+					coordinator_key != Some(public_key),
+					Error::<T>::CoordinatorKeyAsParticipant
+				);
+			}
+
 			// Check that the maximum number of sign-ups has not been reached.
 			ensure!(
 				!poll.registration_limit_reached(),
@@ -701,10 +930,10 @@ pub mod pallet
 			);
 
 			// Record the hash of the registration data.
-			let block = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			let Some(block) = current_block::<T>() else { Err(<Error::<T>>::BlockNumberOutOfRange)? };
 			
 			// Insert the registration data into the poll state.
-			let (count, poll) = poll
+			let (count, filled, poll) = poll
 				.register_participant(public_key, block)
 				.map_err(|error| Error::<T>::PollRegistrationFailed { reason: error.into() })?;
 
@@ -713,6 +942,15 @@ pub mod pallet
 				poll
 			);
 
+			// Record that the signer has registered, independently of the registered key.
+			Registrants::<T>::insert(&poll_id, &sender, ());
+
+			// Record the registered key iff either of the interaction key rules consults it.
+			if T::RejectEphemeralKeyReuse::get() || T::RequireRegisteredInteractions::get()
+			{
+				RegisteredKeys::<T>::insert(&poll_id, &public_key, ());
+			}
+
 			// Emit the registration data for future processing by the coordinator.
 			Self::deposit_event(Event::ParticipantRegistered { 
 				poll_id,
@@ -721,6 +959,15 @@ pub mod pallet
 				block
 			});
 
+			// Announce that the registration tree is full.
+			if filled
+			{
+				Self::deposit_event(Event::PollStateTreeFilled {
+					poll_id,
+					phase: MergePhase::Registration
+				});
+			}
+
 			Ok(())
 		}
 
@@ -735,7 +982,7 @@ pub mod pallet
 		///
 		/// Emits `PollInteraction`.
 		#[pallet::call_index(7)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 2))]
 		pub fn interact_with_poll(
 			origin: OriginFor<T>,
 			poll_id: PollId,
@@ -746,83 +993,1346 @@ pub mod pallet
 			// Ensure that the extrinsic was signed.
 			ensure_signed(origin)?;
 
-			// Ensure that the poll exists and get it.
-			let Some(poll) = Polls::<T>::get(&poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
+			Self::do_interact(poll_id, &[(public_key, data)], None)?;
 
-			// Confirm that the poll is currently within it's voting period.
-			ensure!(!poll.is_registration_period(), Error::<T>::PollRegistrationInProgress);
-			ensure!(!poll.is_over(), Error::<T>::PollVotingHasEnded);
+			Ok(())
+		}
 
-			// Check that we've not reached the maximum number of interactions.
-			ensure!(
-				!poll.interaction_limit_reached(),
-				Error::<T>::ParticipantInteractionLimitReached
-			);
+		/// Permits a signer to interact with an ongoing poll, as per `interact_with_poll`, additionally supplying a cleartext 
+		/// hint of the vote option encrypted within the message. The hint is only used to reject obviously invalid submissions 
+		/// early and is not bound to the encrypted payload; fully private interactions should use `interact_with_poll` instead.
+		///
+		/// - `poll_id`: The index of the poll in storage.
+		/// - `public_key`: The current ephemeral public key of the registrant.
+		/// - `data`: The encrypted interaction data.
+		/// - `vote_option_hint`: The index of the vote option voted for, if disclosed.
+		///
+		/// Emits `PollInteraction`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(4, 2))]
+		pub fn submit_vote(
+			origin: OriginFor<T>,
+			poll_id: PollId,
+			public_key: PublicKey,
+			data: PollInteractionData,
+			vote_option_hint: Option<u32>
+		) -> DispatchResult
+		{
+			// Ensure that the extrinsic was signed.
+			ensure_signed(origin)?;
 
-			// Insert the interaction data into the poll state.
-			let (count, poll) = poll
-				.consume_interaction(public_key, data)
-				.map_err(|error| Error::<T>::PollInteractionFailed { reason: error.into() })?;
+			Self::do_interact(poll_id, &[(public_key, data)], vote_option_hint)?;
 
-			Polls::<T>::insert(
-				&poll_id, 
-				poll
-			);
+			Ok(())
+		}
 
-			// Emit the interaction data for future processing by the coordinator.
-			Self::deposit_event(Event::PollInteraction {
-				poll_id,
-				count,
-				public_key,
-				data
+		/// Permits a coordinator to register verification keys for polls with specific tree depths, replacing any keys
+		/// previously registered for the same depths. Once any depth specific keys are registered, the default verification
+		/// keys are no longer used and polls must be configured with depths for which keys have been registered. Keys may be
+		/// registered for at most `MaxDepthKeys` distinct depths. Rejected if an extant poll is ongoing or awaiting processing.
+		///
+		/// - `registration_depth`: The registration tree depth the keys apply to.
+		/// - `interaction_depth`: The interaction tree depth the keys apply to.
+		/// - `verify_key`: The verification keys for circuits of the given depths.
+		///
+		/// Emits `CoordinatorDepthKeysChanged`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
+		pub fn register_depth_keys(
+			origin: OriginFor<T>,
+			registration_depth: u8,
+			interaction_depth: u8,
+			verify_key: VerifyingKeys
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			// Ensure the verification keys can be serialized as affine points.
+			ensure!(serialize_vkey(verify_key.process.clone()).is_some(), Error::<T>::MalformedKeys);
+			ensure!(serialize_vkey(verify_key.tally.clone()).is_some(), Error::<T>::MalformedKeys);
+
+			// Check if origin is registered as a coordinator.
+			let Some(mut coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
+
+			// Ensure that the most recent poll is not currently in progress and is not missing an outcome, if it exists.
+			if let Some(index) = coordinator.last_poll
+			{
+				if let Some(poll) = Polls::<T>::get(index)
+				{
+					ensure!(
+						poll.is_over() && poll.is_fulfilled(),
+						Error::<T>::PollCurrentlyActive
+					);
+				}
+			}
+
+			// Replace any keys previously registered for the same depths.
+			let depths = (registration_depth, interaction_depth);
+			coordinator.depth_keys.retain(|(d, _)| *d != depths);
+			coordinator.depth_keys
+				.try_push((depths, verify_key.clone()))
+				.map_err(|_| Error::<T>::TooManyDepthKeys)?;
+
+			Coordinators::<T>::insert(&sender, coordinator);
+
+			// Emit the key registration event.
+			Self::deposit_event(Event::CoordinatorDepthKeysChanged {
+				who: sender,
+				registration_depth,
+				interaction_depth,
+				process_fingerprint: verify_key.process.fingerprint(),
+				tally_fingerprint: verify_key.tally.fingerprint(),
+				verify_key
 			});
 
 			Ok(())
 		}
-	}
 
-	fn serialize_vkey(
-		vkey: VerifyKey
-	) -> Option<VerifyingKey::<Bn254>>
-	{
-		let Some(alpha_g1) = G1Affine::deserialize_uncompressed(&*vkey.alpha_g1).ok() else { return None; };
-		let Some(beta_g2) = G2Affine::deserialize_uncompressed(&*vkey.beta_g2).ok() else { return None; };
-		let Some(gamma_g2) = G2Affine::deserialize_uncompressed(&*vkey.gamma_g2).ok() else { return None; };
-		let Some(delta_g2) = G2Affine::deserialize_uncompressed(&*vkey.delta_g2).ok() else { return None; };
-		let gamma_abc_g1 = match vkey.gamma_abc_g1
-			.iter()
-			.map(|g| G1Affine::deserialize_uncompressed(g.as_slice()))
-			.collect::<Result<vec::Vec<G1Affine>, _>>()
+		/// Enables or disables the creation of new polls. Extant polls may continue to be interacted with, merged and 
+		/// processed while poll creation is disabled.
+		///
+		/// - `enabled`: Whether new polls may be created.
+		///
+		/// Emits `PollCreationToggled`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_poll_creation_enabled(
+			origin: OriginFor<T>,
+			enabled: bool
+		) -> DispatchResult
 		{
-			Ok(value) => value,
-			Err(_) => return None
-		};
+			// Only root may toggle poll creation.
+			ensure_root(origin)?;
 
-		Some(VerifyingKey::<Bn254> { alpha_g1, beta_g2, gamma_g2, delta_g2, gamma_abc_g1 })
-	}
+			PollCreationEnabled::<T>::put(enabled);
 
-	fn serialize_proof(
-		proof_data: ProofData
-	) -> Option<Proof::<Bn254>>
-	{
-	    let Some(a) = G1Affine::deserialize_uncompressed(&*proof_data.pi_a).ok() else { return None; };
-	    let Some(b) = G2Affine::deserialize_uncompressed(&*proof_data.pi_b).ok() else { return None; };
-	    let Some(c) = G1Affine::deserialize_uncompressed(&*proof_data.pi_c).ok() else { return None; };
+			Self::deposit_event(Event::PollCreationToggled { enabled });
 
-		Some(Proof::<Bn254> { a, b, c })
-	}
+			Ok(())
+		}
 
-	fn verify_proof(
-		verify_key: VerifyKey,
-		public_inputs: vec::Vec<Fr>,
-		proof_data: ProofData
-	) -> bool
-	{
-		let Some(vk) = serialize_vkey(verify_key) else { return false; };
-		let Some(pvk) = Groth16::<Bn254>::process_vk(&vk).ok() else { return false; };
-		let Some(proof) = serialize_proof(proof_data) else { return false; };
-		let Some(result) = Groth16::<Bn254>::verify_with_processed_vk(&pvk, &public_inputs, &proof).ok() else { return false; };
+		/// Permits the coordinator of a poll whose outcome has been committed, or which has been nullified, to discard the 
+		/// working state of its state trees in order to reclaim storage. The roots, outcome and configuration are retained.
+		///
+		/// - `poll_id`: The id of the poll.
+		///
+		/// Emits `PollPruned`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn prune_poll(
+			origin: OriginFor<T>,
+			poll_id: PollId
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			let Some(poll) = Polls::<T>::get(poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
+			ensure!(poll.coordinator == sender, Error::<T>::NotPollCoordinator);
+
+			// Only finalized polls may be pruned.
+			ensure!(poll.is_fulfilled(), Error::<T>::PollCurrentlyActive);
+
+			Polls::<T>::insert(poll_id, poll.prune());
+
+			Self::deposit_event(Event::PollPruned { poll_id });
+
+			Ok(())
+		}
+
+		/// Permits a signer to submit several interactions with an ongoing poll in a single call, e.g., in order
+		/// to split a weighted vote. Each message is subject to the same restrictions as `interact_with_poll`.
+		/// Messages which would exceed the interaction limit of the poll are discarded, and their weight refunded.
+		///
+		/// - `poll_id`: The index of the poll in storage.
+		/// - `messages`: The ephemeral public keys and encrypted interaction data of each message, in order.
+		///
+		/// Emits `PollInteraction` for each inserted message.
+		#[pallet::call_index(12)]
+		#[pallet::weight(Pallet::<T>::interact_batch_weight(messages.len() as u32))]
+		pub fn interact_batch(
+			origin: OriginFor<T>,
+			poll_id: PollId,
+			messages: PollInteractionBatch<T>
+		) -> DispatchResultWithPostInfo
+		{
+			// Ensure that the extrinsic was signed.
+			ensure_signed(origin)?;
+
+			let inserted = Self::do_interact(poll_id, &messages, None)?;
+
+			Ok(Some(Self::interact_batch_weight(inserted)).into())
+		}
+
+		/// Permits a coordinator to recompute their most recent poll from the list of polls they manage, in the
+		/// event that the two have diverged. Has no effect if they are already consistent.
+		///
+		/// Emits `CoordinatorLastPollResynced` if the most recent poll was updated.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
+		pub fn resync_last_poll(
+			origin: OriginFor<T>
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			let Some(mut coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
+
+			if Self::is_last_poll_synced(&sender) { return Ok(()); }
+
+			let last_poll = CoordinatorPollIds::<T>::get(&sender).last().copied();
+			coordinator.last_poll = last_poll;
+			Coordinators::<T>::insert(&sender, coordinator);
+
+			Self::deposit_event(Event::CoordinatorLastPollResynced {
+				who: sender,
+				last_poll
+			});
+
+			Ok(())
+		}
+
+		/// Create a new poll object where the caller is the designated coordinator, with the poll parameters supplied
+		/// by name. Subject to the same validation as `create_poll`.
+		///
+		/// - `config`: The parameters of the poll, including the optional parameters which `create_poll` leaves as default:
+		///   - `metadata`: An optional description of the poll, of at most `MaxMetadataLen` bytes, committed to by its keccak hash.
+		///   - `reject_empty_messages`: Whether interactions consisting entirely of zeroes should be rejected.
+		///   - `min_interactions`: The minimum number of interactions required for the poll to be tallied, or zero.
+		///   - `max_interaction_depth`: The depth to which the interaction tree may grow once full, or None if it may not grow.
+		///   - `metadata_uri`: An optional pointer to a description of the poll, e.g., an IPFS CID, which is stored verbatim.
+		///   - `allow_override`: Whether participants may override a prior vote by interacting again, otherwise votes are one-shot.
+		///     Polls may only forbid overriding if the coordinator keys were generated for `ALLOW_OVERRIDE_CIRCUIT_VERSION` or later.
+		///
+		/// Emits `PollCreated`.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(4, 3))]
+		pub fn create_poll_v2(
+			origin: OriginFor<T>,
+			config: PollConfigInput
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			Self::do_create_poll(sender, config)
+		}
+
+		/// Permits a coordinator to replace the metadata uri of a poll, e.g., to correct or relocate its description.
+		/// Rejected once the voting period has ended.
+		///
+		/// - `poll_id`: The id of the poll.
+		/// - `metadata_uri`: The new metadata uri, or None to clear it.
+		///
+		/// Emits `PollMetadataUpdated`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_poll_metadata(
+			origin: OriginFor<T>,
+			poll_id: PollId,
+			metadata_uri: Option<vec::Vec<u8>>
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			let Some(mut poll) = Polls::<T>::get(poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
+			ensure!(poll.coordinator == sender, Error::<T>::NotPollCoordinator);
+			ensure!(!poll.is_dead(), Error::<T>::PollInactive);
+			ensure!(!poll.is_over(), Error::<T>::PollVotingHasEnded);
+
+			poll.config.metadata_uri = Self::bound_metadata(metadata_uri.clone())?;
+			Polls::<T>::insert(poll_id, poll);
+
+			Self::deposit_event(Event::PollMetadataUpdated { poll_id, metadata_uri });
+
+			Ok(())
+		}
+
+		/// Permits a coordinator to replace their verification keys while retaining their public key, e.g., in order to
+		/// correct a mis-entered key after registration. The keys may be replaced freely until the coordinator creates a
+		/// poll, after which this is rejected if an extant poll is ongoing or awaiting processing.
+		///
+		/// - `verify_key`: The new verification key for the coordinator.
+		///
+		/// Emits `CoordinatorKeysChanged`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
+		pub fn rotate_verify_key(
+			origin: OriginFor<T>,
+			verify_key: VerifyingKeys
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			// Ensure the verification keys can be serialized as affine points.
+			ensure!(serialize_vkey(verify_key.process.clone()).is_some(), Error::<T>::MalformedKeys);
+			ensure!(serialize_vkey(verify_key.tally.clone()).is_some(), Error::<T>::MalformedKeys);
+
+			// Check if origin is registered as a coordinator.
+			let Some(mut coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
+
+			// Ensure that the most recent poll is not currently in progress and is not missing an outcome, if it exists.
+			if let Some(index) = coordinator.last_poll
+			{
+				if let Some(poll) = Polls::<T>::get(index)
+				{
+					ensure!(
+						poll.is_over() && poll.is_fulfilled(),
+						Error::<T>::PollCurrentlyActive
+					);
+				}
+			}
+
+			coordinator.verify_key = verify_key.clone();
+			let public_key = coordinator.public_key;
+
+			Coordinators::<T>::insert(&sender, coordinator);
+
+			// Emit the key rotation event.
+			Self::deposit_event(Event::CoordinatorKeysChanged {
+				who: sender,
+				public_key,
+				process_fingerprint: verify_key.process.fingerprint(),
+				tally_fingerprint: verify_key.tally.fingerprint(),
+				verify_key
+			});
+
+			Ok(())
+		}
+
+		/// Permits the coordinator to verify proof batches and an outcome as per `commit_outcome`, without advancing the poll
+		/// state, e.g., in order to demonstrate on-chain that a batch is valid separately from committing it.
+		///
+		/// - `batches`: The ordered proofs alongside their new commitments, chained from the stored commitments.
+		/// - `outcome`: The outcome to verify against the final tally commitment, once every batch has been verified.
+		///
+		/// Emits `PollProofVerified` for each verified batch.
+		#[pallet::call_index(17)]
+		#[pallet::weight(Pallet::<T>::commit_outcome_weight(batches.len() as u32))]
+		pub fn verify_outcome_batches(
+			origin: OriginFor<T>,
+			batches: ProofBatches,
+			outcome: Option<PollOutcome>
+		) -> DispatchResultWithPostInfo
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			Self::do_commit_outcome(sender, batches, outcome, None, true)
+		}
+
+		/// Permits the coordinator to commit proof batches and an outcome as per `commit_outcome`, additionally asserting the
+		/// number of proofs which have already been verified and the commitment the first batch continues from, such that a
+		/// resubmitted or reordered call neither skips nor repeats any of the proofs.
+		///
+		/// - `start_index`: The number of proofs the caller expects to have been verified prior to the first batch. Rejected
+		///				 with `CommitmentIndexMismatch` unless the batches continue exactly from the stored commitments.
+		/// - `start_commitment`: The commitment the caller expects the first batch to continue from. This is the latest process
+		///				 commitment until every process proof is verified. The tally chain is seeded from the final process
+		///				 commitment, which the first tally batch continues from, and subsequent tally batches continue from the
+		///				 latest tally commitment. Rejected with `CommitmentIndexMismatch` for a process batch, or with
+		///				 `TallyCommitmentMismatch` for a tally batch, if it does not match.
+		/// - `batches`: The ordered proofs alongside their new commitments.
+		/// - `outcome`: The outcome of the poll, as per `commit_outcome`.
+		///
+		/// Emits as per `commit_outcome`.
+		#[pallet::call_index(18)]
+		#[pallet::weight(Pallet::<T>::commit_outcome_weight(batches.len() as u32))]
+		pub fn commit_outcome_from(
+			origin: OriginFor<T>,
+			start_index: CommitmentIndex,
+			start_commitment: CommitmentData,
+			batches: ProofBatches,
+			outcome: Option<PollOutcome>
+		) -> DispatchResultWithPostInfo
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			Self::do_commit_outcome(sender, batches, outcome, Some((start_index, start_commitment)), false)
+		}
+
+		/// Permits a registered coordinator to describe themselves, e.g., by a name or a pointer to a profile.
+		///
+		/// - `metadata`: The new metadata, of at most `MaxMetadataLen` bytes, or None to clear it.
+		///
+		/// Emits `CoordinatorMetadataUpdated`.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_coordinator_metadata(
+			origin: OriginFor<T>,
+			metadata: Option<vec::Vec<u8>>
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			let Some(mut coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
+
+			coordinator.metadata = Self::bound_metadata(metadata.clone())?;
+			Coordinators::<T>::insert(&sender, coordinator);
+
+			Self::deposit_event(Event::CoordinatorMetadataUpdated { who: sender, metadata });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T>
+	{
+		/// Bounds coordinator supplied metadata by the runtime `MaxMetadataLen`.
+		fn bound_metadata(
+			metadata: Option<vec::Vec<u8>>
+		) -> Result<Option<PollMetadata<T>>, Error<T>>
+		{
+			metadata
+				.map(|data| data.try_into().map_err(|_| Error::<T>::MetadataTooLong))
+				.transpose()
+		}
+
+		/// Validates the poll parameters and inserts a new poll coordinated by `sender`.
+		fn do_create_poll(
+			sender: T::AccountId,
+			config: PollConfigInput
+		) -> DispatchResult
+		{
+			let PollConfigInput {
+				signup_period,
+				voting_period,
+				registration_depth,
+				interaction_depth,
+				process_subtree_depth,
+				tally_subtree_depth,
+				vote_option_tree_depth,
+				vote_options,
+				metadata,
+				reject_empty_messages,
+				min_interactions,
+				max_interaction_depth,
+				metadata_uri,
+				allow_override
+			} = config;
+
+			// Check that poll creation has not been disabled.
+			ensure!(PollCreationEnabled::<T>::get(), Error::<T>::PollCreationDisabled);
+
+			// Validate config parameters.
+			let Some(created_at) = current_block::<T>() else { Err(<Error::<T>>::BlockNumberOutOfRange)? };
+
+			// The block following the voting period must be representable, such that the poll deadlines do not overflow.
+			ensure!(
+				created_at
+					.checked_add(signup_period)
+					.and_then(|block| block.checked_add(voting_period))
+					.and_then(|block| block.checked_add(1))
+					.is_some(),
+				Error::<T>::BlockNumberOutOfRange
+			);
+
+			// Polls must accept participant input for at least the minimum period configured by the runtime.
+			ensure!(
+				signup_period >= T::MinSignupPeriod::get() && voting_period >= T::MinVotingPeriod::get(),
+				Error::<T>::PollConfigInvalid
+			);
+
+			let Some(mut state) = PollState::new(
+				registration_depth,
+				interaction_depth
+			).ok() else { Err(<Error::<T>>::PollConfigInvalid)? };
+
+			// The interaction tree may grow beyond its initial depth up to an optional cap.
+			let max_interaction_depth = max_interaction_depth.unwrap_or(interaction_depth);
+			ensure!(max_interaction_depth >= interaction_depth, Error::<T>::PollConfigInvalid);
+			let Some(interactions) = state.interactions
+				.with_max_depth(max_interaction_depth)
+				.ok() else { Err(<Error::<T>>::PollConfigInvalid)? };
+			state.interactions = interactions;
+
+			// The poll capacity is bounded by the structural capacity of the state trees.
+			let Some(max_registrations) = u32::from(state.registrations.arity)
+				.checked_pow(registration_depth.into()) else { Err(<Error::<T>>::PollConfigInvalid)? };
+			ensure!(
+				max_registrations <= T::MaxPollRegistrations::get(),
+				Error::<T>::PollConfigInvalid
+			);
+			let Some(max_interactions) = u32::from(state.interactions.arity)
+				.checked_pow(max_interaction_depth.into()) else { Err(<Error::<T>>::PollConfigInvalid)? };
+			ensure!(
+				max_interactions <= T::MaxPollInteractions::get(),
+				Error::<T>::PollConfigInvalid
+			);
+			ensure!(
+				min_interactions <= max_interactions,
+				Error::<T>::PollConfigInvalid
+			);
+
+			// Proof batches may not span more than the state tree they process.
+			ensure!(
+				process_subtree_depth <= interaction_depth && tally_subtree_depth <= registration_depth,
+				Error::<T>::PollConfigInvalid
+			);
+
+			ensure!(vote_options.len() > 1, Error::<T>::PollConfigInvalid);
+			let vote_options: VoteOptions<T> = vote_options
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyVoteOptions)?;
+
+			let metadata = Self::bound_metadata(metadata)?;
+			let metadata_uri = Self::bound_metadata(metadata_uri)?;
+
+			// Check that sender is registered as a coordinator.
+			let Some(mut coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
+
+			// Snapshot the circuit revision of the keys which will verify the poll proofs. Once a coordinator has registered
+			// depth specific keys, polls may only be created for depths with registered keys.
+			let Some(verify_keys) = coordinator.verify_keys_for(registration_depth, interaction_depth)
+				else { Err(<Error::<T>>::NoVerifyKeyForDepth)? };
+			let circuit_version = verify_keys.circuit_version;
+
+			// A grown interaction tree is proven against the keys of its merged depth, so keys generated for the same circuits
+			// must be registered for every depth the tree may reach.
+			ensure!(
+				(interaction_depth..=max_interaction_depth).all(|depth| matches!(
+					coordinator.verify_keys_for(registration_depth, depth),
+					Some(keys) if keys.circuit_version == circuit_version
+				)),
+				Error::<T>::NoVerifyKeyForDepth
+			);
+
+			// Overriding may only be forbidden if the message processing circuit takes the flag as a public input.
+			let allow_override = allow_override.unwrap_or(true);
+			ensure!(
+				allow_override || circuit_version >= ALLOW_OVERRIDE_CIRCUIT_VERSION,
+				Error::<T>::PollConfigInvalid
+			);
+
+			let coord_poll_ids = Self::poll_ids(&sender);
+
+			// A coordinator may have at most `MaxCoordinatorPolls` polls, skipped if zero.
+			let max_polls = T::MaxCoordinatorPolls::get() as usize;
+			ensure!(
+				coord_poll_ids.len() < max_polls,
+				Error::<T>::CoordinatorPollLimitReached
+			);
+
+			// A coordinator may only have a single active poll at a given time.
+			if let Some(index) = coord_poll_ids.last()
+			{
+				if let Some(poll) = Polls::<T>::get(index)
+				{
+					// Reject if last created poll is on-going, or has yet to be processed.
+					ensure!(
+						poll.is_over() && poll.is_fulfilled(),
+						Error::<T>::PollCurrentlyActive 
+					);
+				}
+			}
+
+			// A coordinator may not create successive polls within the cooldown configured by the runtime.
+			if let Some(last_created_at) = coordinator.last_poll_created_at
+			{
+				ensure!(
+					created_at >= last_created_at.saturating_add(T::PollCreationCooldown::get()),
+					Error::<T>::PollCreationCooldown
+				);
+			}
+
+			// Insert the poll into storage. Poll ids are never reused, even if polls are later removed from storage.
+			let index = NextPollId::<T>::get();
+			NextPollId::<T>::put(index + 1);
+			Polls::<T>::insert(&index, Poll {
+				index,
+				created_at,
+				coordinator: sender.clone(),
+				state,
+				config: PollConfiguration {
+					signup_period,
+					voting_period,
+					max_registrations,
+					max_interactions,
+					process_subtree_depth,
+					tally_subtree_depth,
+					vote_option_tree_depth,
+					vote_options: vote_options.clone(),
+					metadata_hash: metadata.map(|data| Keccak::hash(&data)),
+					reject_empty_messages,
+					min_interactions,
+					circuit_version,
+					metadata_uri,
+					allow_override,
+					registration_depth,
+					interaction_depth
+				}
+			});
+
+			coordinator.last_poll = Some(index);
+			coordinator.last_poll_created_at = Some(created_at);
+			Coordinators::<T>::insert(&sender, coordinator);
+			CoordinatorPollIds::<T>::append(&sender, index);
+
+			// Emit the creation event. The poll is over from the block following the final block of the voting period.
+			let starts_at = created_at + signup_period;
+			let ends_at = starts_at + voting_period + 1;
+			Self::deposit_event(Event::PollCreated { 
+				coordinator: sender,
+				poll_id: index,
+				starts_at,
+				ends_at,
+				vote_options
+			});
+
+			Ok(())
+		}
+
+		/// Validates a call to merge the state of the most recent poll of `sender`, returning the poll and the state tree
+		/// which is to be merged.
+		fn prepare_merge_poll_state(
+			sender: &T::AccountId
+		) -> Result<(PollId, Poll<T>, MergePhase), DispatchError>
+		{
+			// Get the coordinators most recent poll.
+			let Some(coordinator) = Coordinators::<T>::get(sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
+			let Some(poll_id) = coordinator.last_poll else { Err(<Error::<T>>::PollDoesNotExist)? };
+			let Some(poll) = Polls::<T>::get(poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
+
+			// Ensure that the poll has not been nullified.
+			ensure!(!poll.is_dead(), Error::<T>::PollInactive);
+
+			// Check that the poll is not currently in the registration period.
+			ensure!(
+				!poll.is_registration_period(),
+				Error::<T>::PollRegistrationInProgress
+			);
+
+			let phase = if poll.state.registrations.root.is_none()
+			{
+				// Allow a block for registrations to settle before the registration tree is merged.
+				ensure!(
+					poll.is_registration_settled(),
+					Error::<T>::PollRegistrationInProgress
+				);
+
+				// Ensure that there was at least one registration.
+				ensure!(
+					poll.state.registrations.count > 0,
+					Error::<T>::PollDataEmpty
+				);
+
+				MergePhase::Registration
+			}
+
+			else if poll.state.interactions.root.is_none()
+			{
+				// Check that the poll is not currenltly in the voting period.
+				ensure!(
+					poll.is_over(),
+					Error::<T>::PollVotingInProgress
+				);
+
+				// Ensure that there was at least one interaction, otherwise the poll may only be nullified.
+				ensure!(
+					poll.state.interactions.count > 0,
+					Error::<T>::NoInteractionsToTally
+				);
+
+				// Ensure that the tally would not trivially reveal the interactions.
+				ensure!(
+					poll.state.interactions.count >= poll.config.min_interactions,
+					Error::<T>::InsufficientInteractions
+				);
+
+				MergePhase::Interaction
+			}
+
+			// Poll data has already been merged.
+			else { Err(<Error::<T>>::PollDataEmpty)? };
+
+			Ok((poll_id, poll, phase))
+		}
+
+		/// Validates a call to commit proof batches and an outcome, returning the coordinator, their most recent poll and the
+		/// verify keys of the poll.
+		fn prepare_commit_outcome(
+			sender: &T::AccountId,
+			batches: &ProofBatches,
+			outcome: &Option<PollOutcome>,
+			start: Option<(CommitmentIndex, CommitmentData)>
+		) -> Result<(Coordinator<T>, PollId, Poll<T>, VerifyingKeys), DispatchError>
+		{
+			// Get the coordinators most recent poll.
+			let Some(coordinator) = Coordinators::<T>::get(sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
+			let Some(poll_id) = coordinator.last_poll else { Err(<Error::<T>>::PollDoesNotExist)? };
+			let Some(poll) = Polls::<T>::get(poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
+
+			// Ensure that the poll has not been nullified.
+			ensure!(!poll.is_dead(), Error::<T>::PollInactive);
+
+			// A poll which ended without any interactions is never merged, and may only be nullified.
+			ensure!(
+				!(poll.is_over() && poll.state.interactions.count == 0),
+				Error::<T>::NoInteractionsToTally
+			);
+
+			// Check that the state trees have been merged 
+			ensure!(poll.is_merged(), Error::<T>::PollStateNotMerged);
+
+			// Check that the outcome has not already been committed.
+			ensure!(!poll.is_fulfilled(), Error::<T>::PollOutcomeAlreadyDetermined);
+
+			// Ensure at least one of the inputs have been provided.
+			ensure!(batches.len() > 0 || outcome.is_some(), Error::<T>::MalformedInput);
+
+			// Ensure the batches neither skip nor repeat any of the proofs the caller expects to have been verified, and
+			// continue from the commitment the caller expects.
+			if let Some((start_index, start_commitment)) = start
+			{
+				let commitment = &poll.state.commitment;
+				ensure!(start_index == commitment.verified(), Error::<T>::CommitmentIndexMismatch);
+				ensure!(
+					start_commitment == commitment.current(),
+					Self::commitment_mismatch_error(commitment.is_process_complete())
+				);
+			}
+
+			// Select the verify keys matching the depths of the merged state trees, which may exceed the configured interaction
+			// depth if the interaction tree grew.
+			let Some(verify_keys) = coordinator.verify_keys_for(
+				poll.config.registration_depth,
+				poll.state.interactions.full_depth
+			) else { Err(<Error::<T>>::NoVerifyKeyForDepth)? };
+
+			// Check that the keys were generated for the circuits the poll was created with.
+			ensure!(
+				verify_keys.circuit_version == poll.config.circuit_version,
+				Error::<T>::CircuitVersionMismatch
+			);
+
+			Ok((coordinator, poll_id, poll, verify_keys))
+		}
+
+		/// The error reported for proof batches which do not continue from the expected commitment. Tally batches are reported
+		/// with `TallyCommitmentMismatch`, whether the commitment was declared by the caller or is implied by the stored chain.
+		fn commitment_mismatch_error(
+			is_tally: bool
+		) -> Error<T>
+		{
+			if is_tally { Error::<T>::TallyCommitmentMismatch } else { Error::<T>::CommitmentIndexMismatch }
+		}
+
+		/// The error reported for a proof which failed verification. The public inputs of a tally proof chain it from the
+		/// stored tally commitment, so its rejection is reported as per a declared tally commitment which does not match.
+		fn proof_error(
+			error: VerifyError,
+			is_tally: bool
+		) -> Error<T>
+		{
+			match error
+			{
+				VerifyError::ProofRejected if is_tally => Self::commitment_mismatch_error(true),
+				error => Error::<T>::MalformedProof { reason: error.into() }
+			}
+		}
+
+		/// Verifies the proof batches and outcome against the most recent poll of `sender`, persisting the advanced commitments
+		/// and any verified outcome unless `verify_only` is set. If `start` is provided, the batches must continue from its
+		/// commitment index and commitment.
+		fn do_commit_outcome(
+			sender: T::AccountId,
+			batches: ProofBatches,
+			outcome: Option<PollOutcome>,
+			start: Option<(CommitmentIndex, CommitmentData)>,
+			verify_only: bool
+		) -> DispatchResultWithPostInfo
+		{
+			// Failures prior to verifying any of the batches are only charged for the storage reads.
+			let (coordinator, poll_id, mut poll, verify_keys) = Self::prepare_commit_outcome(&sender, &batches, &outcome, start)
+				.map_err(|error| error.with_weight(Self::commit_outcome_weight(0)))?;
+
+			// Verify each batch of proofs in order.
+			let initial_commitment = poll.state.commitment.clone();
+			let mut rejected = None;
+			for (verified, (proof, new_commitment)) in batches.iter().enumerate()
+			{
+				let is_tally = poll.state.commitment.is_process_complete();
+				let result = poll.clone().prepare_public_inputs(
+					coordinator.public_key,
+					verify_keys.clone(),
+					*new_commitment
+				)
+				.map_err(|error| error.with_weight(Self::commit_outcome_weight(verified as u32)))
+				.and_then(|(verify_key, public_inputs, commitment)| {
+					verify_proof(verify_key, public_inputs, proof.clone())
+						.and_then(|valid| if valid { Ok(commitment) } else { Err(VerifyError::ProofRejected) })
+						.map_err(|error| Self::proof_error(error, is_tally)
+							.with_weight(Self::commit_outcome_weight(verified as u32 + 1)))
+				});
+
+				let commitment = match result
+				{
+					Ok(commitment) => commitment,
+					// Retain the batches verified prior to the first rejected batch, and reject the remainder.
+					Err(error) if verified > 0 && !verify_only => {
+						rejected = Some((verified as u32, error));
+						break;
+					},
+					Err(error) => Err(error)?
+				};
+
+				if verify_only
+				{
+					Self::deposit_event(Event::PollProofVerified {
+						poll_id,
+						commitment: commitment.clone()
+					});
+				}
+
+				poll.state.commitment = commitment;
+			}
+
+			// A verification only call must not persist the advanced commitment.
+			if verify_only
+			{
+				poll.verify_outcome(outcome)?;
+				return Ok(().into());
+			}
+
+			// Publish the commitment of each advanced commitment chain.
+			let commitment = poll.state.commitment.clone();
+			if commitment.process.0 != initial_commitment.process.0
+			{
+				Self::deposit_event(Event::PollCommitmentUpdated {
+					poll_id,
+					phase: CommitmentPhase::Process,
+					index: commitment.process.0,
+					value: commitment.process.1,
+					commitment: commitment.clone()
+				})
+			}
+			if commitment.tally.0 != initial_commitment.tally.0
+			{
+				Self::deposit_event(Event::PollCommitmentUpdated {
+					poll_id,
+					phase: CommitmentPhase::Tally,
+					index: commitment.tally.0,
+					value: commitment.tally.1,
+					commitment
+				})
+			}
+
+			// The outcome is not verified if any of the batches were rejected.
+			if let Some((accepted, error)) = rejected
+			{
+				Polls::<T>::insert(poll_id, poll);
+
+				Self::deposit_event(Event::PollProofBatchRejected {
+					poll_id,
+					accepted,
+					error: error.error
+				});
+
+				return Ok(error.post_info);
+			}
+
+			// Once the final proof batch is verified, verify that the outcome matches the final commitment.
+			let mut tally_results = outcome.as_ref().map(|outcome| outcome.tally_results.clone());
+			if let Some(outcome_index) = poll.clone().verify_outcome(outcome)?
+			{
+				// Record only the tally results which were verified against the final commitment.
+				if let Some(results) = tally_results.as_mut() { results.truncate(poll.config.vote_options.len()); }
+
+				poll.state.outcome = Some(outcome_index);
+				poll.state.tally_results = tally_results;
+
+				// Record the audit summary of the poll.
+				if let Some(summary) = FinalizedPoll::new(&poll, coordinator.public_key)
+				{
+					FinalizedPolls::<T>::insert(poll_id, summary);
+				}
+
+				Self::deposit_event(Event::PollOutcome { 
+					poll_id,
+					outcome_index
+				});
+			}
+
+			// Update the poll state.
+			Polls::<T>::insert(poll_id, poll);
+
+			Ok(().into())
+		}
+
+		/// Validates and inserts the interactions of `messages` into the poll, discarding the messages which would exceed its
+		/// interaction limit, and returns the number of interactions inserted. If `vote_option_hint` is provided it must index
+		/// into the poll vote options.
+		fn do_interact(
+			poll_id: PollId,
+			messages: &[(PublicKey, PollInteractionData)],
+			vote_option_hint: Option<u32>
+		) -> Result<u32, DispatchError>
+		{
+			// Ensure at least one message has been provided.
+			ensure!(!messages.is_empty(), Error::<T>::MalformedInput);
+
+			// Ensure that each of the ephemeral public keys is a valid curve point.
+			ensure!(
+				messages.iter().all(|(public_key, _)| !public_key.is_zero()),
+				Error::<T>::ZeroPublicKey
+			);
+			ensure!(
+				messages.iter().all(|(public_key, _)| public_key.is_on_curve()),
+				Error::<T>::InvalidPublicKey
+			);
+
+			// Ensure that the poll exists and get it.
+			let Some(mut poll) = Polls::<T>::get(&poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
+
+			// Ensure that the poll has not been nullified.
+			ensure!(!poll.is_dead(), Error::<T>::PollInactive);
+
+			// Check that the hinted vote option exists, if one was provided.
+			if let Some(option_index) = vote_option_hint
+			{
+				ensure!(
+					(option_index as usize) < poll.config.vote_options.len(),
+					Error::<T>::VoteOptionOutOfRange
+				);
+			}
+
+			// Confirm that the poll is currently within it's voting period.
+			ensure!(!poll.is_registration_period(), Error::<T>::PollRegistrationInProgress);
+			ensure!(!poll.is_over(), Error::<T>::PollVotingHasEnded);
+
+			// Check that at least one message may be inserted.
+			ensure!(
+				!poll.interaction_limit_reached(),
+				Error::<T>::ParticipantInteractionLimitReached
+			);
+
+			// Reject uninitialized message buffers if the poll requires it.
+			ensure!(
+				!poll.config.reject_empty_messages || messages.iter().all(|(_, data)| data.iter().any(|field| *field != [0u8; 32])),
+				Error::<T>::EmptyInteractionData
+			);
+
+			// Optionally reject ephemeral keys which were used to register in the poll.
+			ensure!(
+				!messages.iter().any(|(public_key, _)| Self::is_ephemeral_key_reused(poll_id, public_key)),
+				Error::<T>::EphemeralKeyReused
+			);
+
+			// Optionally reject keys which were not used to register in the poll.
+			ensure!(
+				!messages.iter().any(|(public_key, _)| Self::is_interaction_key_unregistered(poll_id, public_key)),
+				Error::<T>::UnregisteredInteraction
+			);
+
+			let mut inserted: u32 = 0;
+			for &(public_key, data) in messages
+			{
+				// Discard the remaining messages once the maximum number of interactions is reached.
+				if poll.interaction_limit_reached() { break; }
+
+				// Insert the interaction data into the poll state.
+				let (count, filled, next) = poll
+					.consume_interaction(public_key, data)
+					.map_err(|error| Error::<T>::PollInteractionFailed { reason: error.into() })?;
+				poll = next;
+				inserted += 1;
+				let depth = poll.state.interactions.depth;
+
+				// Track the number of interactions submitted under the public key.
+				InteractionCounts::<T>::mutate(&poll_id, &public_key, |count| *count = count.saturating_add(1));
+
+				// Emit the interaction data for future processing by the coordinator.
+				Self::deposit_event(Event::PollInteraction {
+					poll_id,
+					count,
+					depth,
+					public_key,
+					data
+				});
+
+				// Announce that the interaction tree is full.
+				if filled
+				{
+					Self::deposit_event(Event::PollStateTreeFilled {
+						poll_id,
+						phase: MergePhase::Interaction
+					});
+				}
+			}
+
+			Polls::<T>::insert(
+				&poll_id,
+				poll
+			);
+
+			Ok(inserted)
+		}
+
+		/// Returns true iff the runtime rejects ephemeral key reuse and `public_key` was registered in the poll.
+		pub fn is_ephemeral_key_reused(
+			poll_id: PollId,
+			public_key: &PublicKey
+		) -> bool
+		{
+			T::RejectEphemeralKeyReuse::get() && RegisteredKeys::<T>::contains_key(poll_id, public_key)
+		}
+
+		/// Returns true iff the runtime requires registered interaction keys and `public_key` was not registered in the poll.
+		pub fn is_interaction_key_unregistered(
+			poll_id: PollId,
+			public_key: &PublicKey
+		) -> bool
+		{
+			T::RequireRegisteredInteractions::get() && !RegisteredKeys::<T>::contains_key(poll_id, public_key)
+		}
+
+		/// Returns true iff `who` has signed a registration in the poll.
+		pub fn has_registered(
+			poll_id: PollId,
+			who: &T::AccountId
+		) -> bool
+		{
+			Registrants::<T>::contains_key(poll_id, who)
+		}
+
+		/// Returns true iff the most recent poll of the coordinator is the last of the polls they manage,
+		/// or if `who` is not a coordinator.
+		pub fn is_last_poll_synced(
+			who: &T::AccountId
+		) -> bool
+		{
+			Coordinators::<T>::get(who).map_or(true, |coordinator| {
+				coordinator.last_poll == CoordinatorPollIds::<T>::get(who).last().copied()
+			})
+		}
+
+		/// Returns the number of hashes the next call to `merge_poll_state` will compute for the poll.
+		pub fn pending_merge_cost(
+			poll_id: PollId
+		) -> Option<u32>
+		{
+			let poll = Polls::<T>::get(poll_id)?;
+
+			if poll.state.registrations.root.is_none()
+			{
+				Some(poll.state.registrations.pending_merge_cost(false))
+			}
+			else { Some(poll.state.interactions.pending_merge_cost(true)) }
+		}
+
+		/// Returns the state tree which the next call to `merge_poll_state` will merge for the poll,
+		/// or None if both of the state trees have been merged.
+		pub fn next_merge_phase(
+			poll_id: PollId
+		) -> Option<MergePhase>
+		{
+			let poll = Polls::<T>::get(poll_id)?;
+
+			if poll.state.registrations.root.is_none() { Some(MergePhase::Registration) }
+			else if poll.state.interactions.root.is_none() { Some(MergePhase::Interaction) }
+			else { None }
+		}
+
+		/// Returns the depth of the registration tree which is supplied to the message processing circuit as a public input,
+		/// or None if the registration tree has not been merged. This is the depth of the subtree consisting of the non-zero
+		/// leaves, rather than `full_depth`, and hence depends on turnout.
+		pub fn registration_state_depth(
+			poll_id: PollId
+		) -> Option<u8>
+		{
+			let poll = Polls::<T>::get(poll_id)?;
+			poll.state.registrations.root?;
+
+			Some(poll.state.registrations.depth)
+		}
+
+		/// Returns the root of the registration tree, computing the merge without persisting it if the tree is not yet merged.
+		pub fn preview_registration_root(
+			poll_id: PollId
+		) -> Option<HashBytes>
+		{
+			let poll = Polls::<T>::get(poll_id)?;
+			if poll.state.registrations.root.is_some() { return poll.state.registrations.root; }
+
+			poll.state.registrations.merge(false).ok()?.root
+		}
+
+		/// Returns the digest of the Poseidon parameters for the state `width`.
+		pub fn poseidon_params_digest(
+			width: u8
+		) -> Option<HashBytes>
+		{
+			hash::parameters::get_poseidon_parameters::<Fr>(width)
+				.ok()
+				.map(|params| params.digest())
+		}
+
+		/// Returns the account of the coordinator which manages the poll.
+		pub fn poll_coordinator(
+			poll_id: PollId
+		) -> Option<T::AccountId>
+		{
+			Polls::<T>::get(poll_id).map(|poll| poll.coordinator)
+		}
+
+		/// Returns the public key of the coordinator, to which participants encrypt their interactions.
+		pub fn coordinator_public_key(
+			who: &T::AccountId
+		) -> Option<PublicKey>
+		{
+			Coordinators::<T>::get(who).map(|coordinator| coordinator.public_key)
+		}
+
+		/// Returns the default verify keys of the coordinator.
+		pub fn coordinator_verify_keys(
+			who: &T::AccountId
+		) -> Option<VerifyingKeys>
+		{
+			Coordinators::<T>::get(who).map(|coordinator| coordinator.verify_key)
+		}
+
+		/// Returns the options which may be voted for in the poll.
+		pub fn vote_options(
+			poll_id: PollId
+		) -> Option<vec::Vec<u128>>
+		{
+			Polls::<T>::get(poll_id).map(|poll| poll.config.vote_options.into_inner())
+		}
+
+		/// Returns the last block of the voting period of the poll, which is supplied to the message processing circuit
+		/// as a public input. NB this precedes the `ends_at` block of the `PollCreated` event, from which the poll is over, by one.
+		pub fn voting_period_end(
+			poll_id: PollId
+		) -> Option<BlockNumber>
+		{
+			Polls::<T>::get(poll_id).map(|poll| poll.get_voting_period_end())
+		}
+
+		/// Returns true iff the coordinator of the poll may commit proofs of its outcome.
+		pub fn can_commit(
+			poll_id: PollId
+		) -> bool
+		{
+			Polls::<T>::get(poll_id).is_some_and(|poll| poll.can_commit_outcome())
+		}
+
+		/// Returns the registration root, interaction root, and commitment of the poll, such that the cryptographic state
+		/// of the poll may be retrieved in a single read.
+		pub fn poll_roots(
+			poll_id: PollId
+		) -> Option<(Option<HashBytes>, Option<HashBytes>, Commitment)>
+		{
+			Polls::<T>::get(poll_id).map(|poll| (
+				poll.state.registrations.root,
+				poll.state.interactions.root,
+				poll.state.commitment
+			))
+		}
+
+		/// Returns the ids of the polls with the given status amongst at most `max` polls, in ascending order starting from
+		/// `start`, alongside the id from which to resume, or None if every poll has been visited.
+		pub fn polls_by_status(
+			status: PollStatus,
+			start: PollId,
+			max: u32
+		) -> (vec::Vec<PollId>, Option<PollId>)
+		{
+			let next_poll_id = NextPollId::<T>::get();
+			let end = start.saturating_add(max).min(next_poll_id);
+
+			let poll_ids = (start..end)
+				.filter(|poll_id| Polls::<T>::get(poll_id).is_some_and(|poll| poll.status() == status))
+				.collect();
+
+			(poll_ids, if end < next_poll_id { Some(end) } else { None })
+		}
+
+		/// Returns the current phase of the poll and the number of blocks remaining in it, or None if the poll has ended.
+		pub fn poll_countdown(
+			poll_id: PollId
+		) -> Option<(PollStatus, BlockNumber)>
+		{
+			Polls::<T>::get(poll_id)?.blocks_remaining()
+		}
+
+		/// Returns true iff the recorded outcome of the poll is the vote option with the largest recorded tally,
+		/// or None if the poll has no recorded outcome.
+		pub fn verify_declared_outcome(
+			poll_id: PollId
+		) -> Option<bool>
+		{
+			let poll = Polls::<T>::get(poll_id)?;
+			let outcome_index = poll.state.outcome?;
+			let tally_results = poll.state.tally_results?;
+
+			Some(poll::tally_argmax(&tally_results) == outcome_index)
+		}
+
+		/// Returns the commitment which the first process proof must chain from, once the registration tree has been merged.
+		pub fn initial_process_commitment(
+			poll_id: PollId
+		) -> Option<HashBytes>
+		{
+			let root = Polls::<T>::get(poll_id)?.state.registrations.root?;
+
+			poll::initial_process_commitment(root).ok()
+		}
+
+		/// Returns the public inputs which will be supplied to the verifier for the proof at `proof_index`,
+		/// chained from `curr_commitment`. Process proofs are indexed first, followed by the tally proofs.
+		pub fn proof_public_inputs(
+			poll_id: PollId,
+			proof_index: CommitmentIndex,
+			curr_commitment: HashBytes,
+			new_commitment: HashBytes
+		) -> Option<vec::Vec<HashBytes>>
+		{
+			let mut poll = Polls::<T>::get(poll_id)?;
+			if !poll.is_merged() { return None; }
+
+			let coordinator = Coordinators::<T>::get(&poll.coordinator)?;
+			let verify_keys = coordinator.verify_keys_for(
+				poll.config.registration_depth,
+				poll.state.interactions.full_depth
+			)?;
+
+			let expected_process = poll.state.commitment.expected_process;
+			if proof_index < expected_process
+			{
+				poll.state.commitment.process = (proof_index, curr_commitment);
+			}
+			else
+			{
+				poll.state.commitment.process.0 = expected_process;
+				poll.state.commitment.tally = (proof_index - expected_process, curr_commitment);
+			}
+
+			let (_, inputs, _) = poll.prepare_public_inputs(
+				coordinator.public_key,
+				verify_keys,
+				new_commitment
+			).ok()?;
+
+			Some(inputs
+				.iter()
+				.map(|input| {
+					let bytes = input.into_bigint().to_bytes_be();
+					let mut hash = [0u8; 32];
+					hash[..bytes.len()].copy_from_slice(&bytes);
+					hash
+				})
+				.collect())
+		}
+
+		/// Verifies a proof against the supplied verification key rather than the keys of a coordinator, e.g., in order
+		/// to validate proofs for a proposed circuit revision. Public inputs are encoded as by `proof_public_inputs`.
+		pub fn verify_proof_with_key(
+			verify_key: VerifyKey,
+			public_inputs: vec::Vec<HashBytes>,
+			proof: ProofData
+		) -> bool
+		{
+			let public_inputs = public_inputs
+				.iter()
+				.map(|input| Fr::from_be_bytes_mod_order(input))
+				.collect();
+
+			verify_proof(verify_key, public_inputs, proof).unwrap_or(false)
+		}
+
+		/// Returns the weight of committing `batches` proofs to a poll.
+		pub fn commit_outcome_weight(
+			batches: u32
+		) -> Weight
+		{
+			T::DbWeight::get()
+				.reads_writes(2, 2)
+				.saturating_add(T::WeightInfo::verify_proof().saturating_mul(batches.into()))
+		}
+
+		/// Returns the weight of merging a poll state tree by computing `hashes` hashes.
+		pub fn merge_poll_state_weight(
+			hashes: u32
+		) -> Weight
+		{
+			T::DbWeight::get()
+				.reads_writes(2, 1)
+				.saturating_add(T::WeightInfo::merge_hash().saturating_mul(hashes.into()))
+		}
+
+		/// Returns the weight of submitting `messages` interactions to a poll.
+		pub fn interact_batch_weight(
+			messages: u32
+		) -> Weight
+		{
+			T::DbWeight::get()
+				.reads_writes(1, 1)
+				.saturating_add(T::DbWeight::get().reads_writes(2, 1).saturating_mul(messages.into()))
+		}
+	}
+
+	fn serialize_vkey(
+		vkey: VerifyKey
+	) -> Option<VerifyingKey::<Bn254>>
+	{
+		let Some(alpha_g1) = G1Affine::deserialize_uncompressed(&*vkey.alpha_g1).ok() else { return None; };
+		let Some(beta_g2) = G2Affine::deserialize_uncompressed(&*vkey.beta_g2).ok() else { return None; };
+		let Some(gamma_g2) = G2Affine::deserialize_uncompressed(&*vkey.gamma_g2).ok() else { return None; };
+		let Some(delta_g2) = G2Affine::deserialize_uncompressed(&*vkey.delta_g2).ok() else { return None; };
+		let gamma_abc_g1 = match vkey.gamma_abc_g1
+			.iter()
+			.map(|g| G1Affine::deserialize_uncompressed(g.as_slice()))
+			.collect::<Result<vec::Vec<G1Affine>, _>>()
+		{
+			Ok(value) => value,
+			Err(_) => return None
+		};
+
+		Some(VerifyingKey::<Bn254> { alpha_g1, beta_g2, gamma_g2, delta_g2, gamma_abc_g1 })
+	}
+
+	fn serialize_proof(
+		proof_data: ProofData
+	) -> Option<Proof::<Bn254>>
+	{
+	    let Some(a) = G1Affine::deserialize_uncompressed(&*proof_data.pi_a).ok() else { return None; };
+	    let Some(b) = G2Affine::deserialize_uncompressed(&*proof_data.pi_b).ok() else { return None; };
+	    let Some(c) = G1Affine::deserialize_uncompressed(&*proof_data.pi_c).ok() else { return None; };
+
+		Some(Proof::<Bn254> { a, b, c })
+	}
+
+	/// Verifies a groth16 proof, reporting the stage at which verification failed.
+	pub(crate) fn verify_proof(
+		verify_key: VerifyKey,
+		public_inputs: vec::Vec<Fr>,
+		proof_data: ProofData
+	) -> Result<bool, VerifyError>
+	{
+		let Some(vk) = serialize_vkey(verify_key) else {
+			log::debug!(target: LOG_TARGET, "verifying key could not be deserialized");
+			Err(VerifyError::VerifyKeyMalformed)?
+		};
+		let Some(pvk) = Groth16::<Bn254>::process_vk(&vk).ok() else {
+			log::debug!(target: LOG_TARGET, "verifying key could not be processed");
+			Err(VerifyError::VerifyKeyUnprocessable)?
+		};
+		let Some(proof) = serialize_proof(proof_data) else {
+			log::debug!(target: LOG_TARGET, "proof could not be deserialized");
+			Err(VerifyError::ProofMalformed)?
+		};
+		let Some(result) = Groth16::<Bn254>::verify_with_processed_vk(&pvk, &public_inputs, &proof).ok() else {
+			log::debug!(target: LOG_TARGET, "pairing check failed for {} public inputs", public_inputs.len());
+			Err(VerifyError::VerificationFailed)?
+		};
+		log::debug!(target: LOG_TARGET, "proof verified with result {}", result);
 
-		result
+		Ok(result)
 	}
 }
@@ -2,8 +2,10 @@ use crate::*;
 use crate as pallet_infimum;
 use frame_support::{
     derive_impl,
-	traits::{ConstU32, ConstU64}
+	parameter_types,
+	traits::{ConstU32, ConstU64, EnsureOrigin}
 };
+use frame_system::{EnsureSigned, RawOrigin};
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
@@ -46,12 +48,54 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	pub static RestrictCoordinators: bool = false;
+	pub static ApprovedCoordinators: Vec<u64> = Vec::from([0]);
+	pub static RejectCoordinatorKey: bool = false;
+	pub static RejectKeyReuse: bool = false;
+	pub static RequireRegisteredKeys: bool = false;
+	pub static MinSignupPeriod: u64 = 0;
+	pub static MinVotingPeriod: u64 = 0;
+	pub static PollCreationCooldown: u64 = 0;
+}
+
+/// Permits any signed origin to register as a coordinator, unless registration has been restricted.
+pub struct EnsureCoordinator;
+
+impl EnsureOrigin<RuntimeOrigin> for EnsureCoordinator {
+	type Success = u64;
+
+	fn try_origin(origin: RuntimeOrigin) -> Result<u64, RuntimeOrigin> {
+		let who = EnsureSigned::<u64>::try_origin(origin)?;
+		if RestrictCoordinators::get() && !ApprovedCoordinators::get().contains(&who) {
+			return Err(RawOrigin::Signed(who).into());
+		}
+		Ok(who)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RawOrigin::Signed(0).into())
+	}
+}
+
 impl Config for Test {
     type MaxCoordinatorPolls = ConstU32<2>;
     type MaxVoteOptions = ConstU32<1024>;
     type MaxPollRegistrations = ConstU32<2_147_483_648>;
     type MaxPollInteractions = ConstU32<1024>;
+    type MaxIterationDepth = ConstU32<8>;
+    type RejectCoordinatorKeyAsParticipant = RejectCoordinatorKey;
+    type RejectEphemeralKeyReuse = RejectKeyReuse;
+    type RequireRegisteredInteractions = RequireRegisteredKeys;
+    type MinSignupPeriod = MinSignupPeriod;
+    type MinVotingPeriod = MinVotingPeriod;
+    type PollCreationCooldown = PollCreationCooldown;
+    type MaxMetadataLen = ConstU32<64>;
+    type MaxDepthKeys = ConstU32<4>;
 	type RuntimeEvent = RuntimeEvent;
+	type CoordinatorOrigin = EnsureCoordinator;
+	type WeightInfo = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -1,9 +1,16 @@
 use frame_support::pallet_prelude::*;
+use sp_std::vec;
 
-use crate::poll::{BlockNumber, VoteOptions};
+use crate::poll::{BlockNumber, HashBytes, PollMetadata, VoteOptions};
+
+/// The first circuit revision whose message processing circuit declares the `allow_override` flag as its final public
+/// input. Proofs for polls created with keys of this revision or later are always supplied the flag.
+pub const ALLOW_OVERRIDE_CIRCUIT_VERSION: u32 = 1;
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
 pub struct PollConfiguration<T: crate::Config>
 {
     /// The number of blocks for which the registration period is active.
@@ -15,7 +22,7 @@ pub struct PollConfiguration<T: crate::Config>
     /// The maximum number of participants permitted.
     pub max_registrations: u32,
 
-    /// The maximum number of interactions permitted.
+    /// The maximum number of interactions permitted. Never exceeds the runtime `MaxPollInteractions`.
     pub max_interactions: u32,
 
     /// The subtree depth to process per commitment.
@@ -29,4 +36,80 @@ pub struct PollConfiguration<T: crate::Config>
 
     /// The possible outcomes of the poll.
     pub vote_options: VoteOptions<T>,
+
+    /// The keccak hash of the poll description, if one was provided.
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::option_bytes"))]
+    pub metadata_hash: Option<HashBytes>,
+
+    /// Whether interactions consisting entirely of zeroes are rejected.
+    pub reject_empty_messages: bool,
+
+    /// The minimum number of interactions required for the poll to be tallied.
+    pub min_interactions: u32,
+
+    /// The revision of the circuits the coordinator keys were generated for at poll creation.
+    pub circuit_version: u32,
+
+    /// A pointer to a description of the poll, e.g., an IPFS CID, which the coordinator may update.
+    pub metadata_uri: Option<PollMetadata<T>>,
+
+    /// Whether participants may override a prior vote by interacting again. Supplied to the message processing circuit
+    /// as its final public input from `ALLOW_OVERRIDE_CIRCUIT_VERSION`, such that the circuit may enforce it.
+    pub allow_override: bool,
+
+    /// The initial depth of the registration tree, which selects the coordinator verify keys.
+    pub registration_depth: u8,
+
+    /// The initial depth of the interaction tree. Unlike the interaction tree `full_depth`, it does not change as the tree
+    /// grows, hence the verify keys are selected by the merged `full_depth` instead.
+    pub interaction_depth: u8,
+}
+
+/// The parameters of a new poll, as supplied to `create_poll_v2`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PollConfigInput
+{
+    /// The number of blocks for which the registration period is active.
+    pub signup_period: BlockNumber,
+
+    /// The number of blocks for which the voting period is active.
+    pub voting_period: BlockNumber,
+
+    /// The initial depth of the registration tree.
+    pub registration_depth: u8,
+
+    /// The initial depth of the interaction tree.
+    pub interaction_depth: u8,
+
+    /// The subtree depth to process per commitment.
+    pub process_subtree_depth: u8,
+
+    /// The intermediate tally subtree depth.
+    pub tally_subtree_depth: u8,
+
+    /// The vote option tree depth.
+    pub vote_option_tree_depth: u8,
+
+    /// The possible outcomes of the poll.
+    pub vote_options: vec::Vec<u128>,
+
+    /// An optional description of the poll, committed to by its keccak hash.
+    pub metadata: Option<vec::Vec<u8>>,
+
+    /// Whether interactions consisting entirely of zeroes are rejected.
+    pub reject_empty_messages: bool,
+
+    /// The minimum number of interactions required for the poll to be tallied, or zero.
+    pub min_interactions: u32,
+
+    /// The depth to which the interaction tree may grow once full, or None if it may not grow.
+    pub max_interaction_depth: Option<u8>,
+
+    /// An optional pointer to a description of the poll, e.g., an IPFS CID, which is stored verbatim.
+    pub metadata_uri: Option<vec::Vec<u8>>,
+
+    /// Whether participants may override a prior vote by interacting again, or None to permit overriding.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub allow_override: Option<bool>,
 }
@@ -2,9 +2,12 @@ use frame_support::pallet_prelude::*;
 use sp_std::vec;
 
 use crate::poll::{
+    BlockNumber,
     CommitmentIndex,
     CommitmentData,
+    DepthKeys,
     PollId,
+    PollMetadata,
     PublicKey,
     VerifyKey,
     HashBytes
@@ -12,7 +15,10 @@ use crate::poll::{
 
 /// Coordinator storage definition.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
-pub struct Coordinator
+#[scale_info(skip_type_params(T))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct Coordinator<T: crate::Config>
 {
     /// The coordinators public key.
     pub public_key: PublicKey,
@@ -21,16 +27,48 @@ pub struct Coordinator
     pub verify_key: VerifyingKeys,
 
     /// The coordinators most recent poll (may be active).
-    pub last_poll: Option<PollId>
+    pub last_poll: Option<PollId>,
+
+    /// The verify keys for specific (registration, interaction) tree depths. Once non-empty, 
+    /// polls must be configured with depths for which a verify key has been registered.
+    pub depth_keys: DepthKeys<T>,
+
+    /// The block at which the coordinator most recently created a poll.
+    pub last_poll_created_at: Option<BlockNumber>,
+
+    /// A short description of the coordinator, e.g., a name or a pointer to a profile.
+    pub metadata: Option<PollMetadata<T>>
+}
+
+impl<T: crate::Config> Coordinator<T>
+{
+    /// Returns the verify keys for polls with the given tree depths, falling back to the 
+    /// default verify keys if no depth specific keys have been registered.
+    pub fn verify_keys_for(
+        &self,
+        registration_depth: u8,
+        interaction_depth: u8
+    ) -> Option<VerifyingKeys>
+    {
+        if self.depth_keys.is_empty() { return Some(self.verify_key.clone()); }
+
+        self.depth_keys
+            .iter()
+            .find(|(depths, _)| *depths == (registration_depth, interaction_depth))
+            .map(|(_, keys)| keys.clone())
+    }
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Commitment
 {
     /// The commitment to the message processing circuit. 
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::indexed_bytes"))]
     pub process: (CommitmentIndex, CommitmentData),
 
     /// The commitment to the tallying circuit.
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::indexed_bytes"))]
     pub tally: (CommitmentIndex, CommitmentData),
 
     /// The expected number of process commitments.
@@ -40,6 +78,81 @@ pub struct Commitment
     pub expected_tally: CommitmentIndex
 }
 
+impl Commitment
+{
+    /// The number of proofs verified across both commitment chains.
+    pub fn verified(&self) -> CommitmentIndex
+    {
+        self.process.0.saturating_add(self.tally.0)
+    }
+
+    /// Returns true iff every expected process proof has been verified, such that the next batch is a tally proof.
+    pub fn is_process_complete(&self) -> bool
+    {
+        self.process.0 >= self.expected_process
+    }
+
+    /// The commitment which the next proof batch continues from. The tally chain is seeded from the final commitment of
+    /// the process chain, which every tally proof takes as its `sbCommitment`, and thereafter continues from its latest
+    /// commitment. NB the first tally proof takes the zero commitment as its `currentTallyCommitment`.
+    pub fn current(&self) -> CommitmentData
+    {
+        if !self.is_process_complete() || self.tally.0 == 0 { self.process.1 }
+        else { self.tally.1 }
+    }
+
+    /// The latest commitment of the message processing chain, i.e., the current commitment of the next process batch.
+    pub fn process_commitment_bytes(&self) -> CommitmentData
+    {
+        self.process.1
+    }
+
+    /// The latest commitment of the tally chain, i.e., the current commitment of the next tally batch.
+    pub fn tally_commitment_bytes(&self) -> CommitmentData
+    {
+        self.tally.1
+    }
+
+    /// Advances the commitment by the new commitments of `batches`, in the order they are supplied to `commit_outcome`,
+    /// or returns None if there are more batches than remaining expected proofs.
+    ///
+    /// Each batch advances the process chain until `expected_process` proofs are verified, and the tally chain
+    /// thereafter. The new commitment of a batch is the `newSbCommitment` (process) or `newTallyCommitment` (tally)
+    /// public signal of its proof, which the coordinator derives off-chain alongside the witness, and it becomes the
+    /// current commitment of the following batch of the same chain. The tally chain starts from the zero commitment.
+    pub fn with_batches(
+        mut self,
+        batches: &[CommitmentData]
+    ) -> Option<Self>
+    {
+        for commitment in batches
+        {
+            if self.process.0 < self.expected_process
+            {
+                self.process = (self.process.0 + 1, *commitment);
+            }
+            else if self.tally.0 < self.expected_tally
+            {
+                self.tally = (self.tally.0 + 1, *commitment);
+            }
+            else { return None; }
+        }
+
+        Some(self)
+    }
+}
+
+/// The commitment chain advanced by a proof batch.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum CommitmentPhase
+{
+    /// The message processing commitment chain.
+    Process,
+
+    /// The tally commitment chain.
+    Tally
+}
+
 /// A serialized groth16 proof.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct ProofData
@@ -49,6 +162,51 @@ pub struct ProofData
     pub pi_c: vec::Vec<u8>
 }
 
+/// The stage at which the verification of a proof batch failed.
+#[derive(Debug)]
+pub enum VerifyError
+{
+    /// The verifying key could not be deserialized. Reported as [`VerifyError::VERIFY_KEY_MALFORMED`].
+    VerifyKeyMalformed,
+    /// The verifying key could not be prepared for verification. Reported as [`VerifyError::VERIFY_KEY_UNPROCESSABLE`].
+    VerifyKeyUnprocessable,
+    /// The proof could not be deserialized. Reported as [`VerifyError::PROOF_MALFORMED`].
+    ProofMalformed,
+    /// The pairing check could not be performed, e.g., due to an unexpected number of public inputs. Reported as [`VerifyError::VERIFICATION_FAILED`].
+    VerificationFailed,
+    /// The pairing check was performed but the proof is invalid. Reported as [`VerifyError::PROOF_REJECTED`].
+    ProofRejected,
+    /// The public inputs of the proof could not be computed. Reported as [`VerifyError::PUBLIC_INPUTS_INVALID`].
+    PublicInputsInvalid
+}
+
+/// The `reason` codes carried by the `MalformedProof` pallet error.
+impl VerifyError
+{
+    pub const VERIFY_KEY_MALFORMED: u8 = 1;
+    pub const VERIFY_KEY_UNPROCESSABLE: u8 = 2;
+    pub const PROOF_MALFORMED: u8 = 3;
+    pub const VERIFICATION_FAILED: u8 = 4;
+    pub const PROOF_REJECTED: u8 = 5;
+    pub const PUBLIC_INPUTS_INVALID: u8 = 6;
+}
+
+impl From<VerifyError> for u8
+{
+    fn from(error: VerifyError) -> Self
+    {
+        match error
+        {
+            VerifyError::VerifyKeyMalformed => VerifyError::VERIFY_KEY_MALFORMED,
+            VerifyError::VerifyKeyUnprocessable => VerifyError::VERIFY_KEY_UNPROCESSABLE,
+            VerifyError::ProofMalformed => VerifyError::PROOF_MALFORMED,
+            VerifyError::VerificationFailed => VerifyError::VERIFICATION_FAILED,
+            VerifyError::ProofRejected => VerifyError::PROOF_REJECTED,
+            VerifyError::PublicInputsInvalid => VerifyError::PUBLIC_INPUTS_INVALID,
+        }
+    }
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct PollOutcome
 {
@@ -76,11 +234,15 @@ pub struct PollOutcome
 
 /// A pair of verification keys for message processing and tally verification circuits.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VerifyingKeys
 {
     /// The verifying key for the message processing circuit.
     pub process: VerifyKey,
 
     /// The verifying key for the tally circuit.
-    pub tally: VerifyKey
+    pub tally: VerifyKey,
+
+    /// The revision of the circuits the keys were generated for.
+    pub circuit_version: u32
 }
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+use sp_std::vec;
+
+/// A byte array which is (de)serialized as a `0x` prefixed hex string.
+struct Hex<B>(B);
+
+impl<B: AsRef<[u8]>> Serialize for Hex<B>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        let bytes = self.0.as_ref();
+        let mut encoded = String::with_capacity(2 + 2 * bytes.len());
+        encoded.push_str("0x");
+        for byte in bytes { encoded.push_str(&format!("{:02x}", byte)); }
+
+        serializer.serialize_str(&encoded)
+    }
+}
+
+impl<'de, B: TryFrom<vec::Vec<u8>>> Deserialize<'de> for Hex<B>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+    {
+        let encoded = String::deserialize(deserializer)?;
+        let digits = encoded.strip_prefix("0x").unwrap_or(&encoded);
+        if !digits.is_ascii() || digits.len() % 2 != 0 { Err(D::Error::custom("malformed hex string"))? }
+
+        let bytes = (0..digits.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&digits[i..i + 2], 16))
+            .collect::<Result<vec::Vec<u8>, _>>()
+            .map_err(D::Error::custom)?;
+
+        let length = bytes.len();
+        B::try_from(bytes)
+            .map(Hex)
+            .map_err(|_| D::Error::custom(format!("unexpected byte length {}", length)))
+    }
+}
+
+/// A byte array.
+pub mod bytes
+{
+    use super::*;
+
+    pub fn serialize<S: Serializer, B: AsRef<[u8]>>(value: &B, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        Hex(value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, B: TryFrom<vec::Vec<u8>>>(deserializer: D) -> Result<B, D::Error>
+    {
+        Hex::<B>::deserialize(deserializer).map(|hex| hex.0)
+    }
+}
+
+/// An optional byte array.
+pub mod option_bytes
+{
+    use super::*;
+
+    pub fn serialize<S: Serializer, B: AsRef<[u8]>>(value: &Option<B>, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        value.as_ref().map(Hex).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, B: TryFrom<vec::Vec<u8>>>(deserializer: D) -> Result<Option<B>, D::Error>
+    {
+        Option::<Hex<B>>::deserialize(deserializer).map(|value| value.map(|hex| hex.0))
+    }
+}
+
+/// A sequence of byte arrays.
+pub mod vec_bytes
+{
+    use super::*;
+
+    pub fn serialize<S: Serializer, B: AsRef<[u8]>>(value: &vec::Vec<B>, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        serializer.collect_seq(value.iter().map(Hex))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, B: TryFrom<vec::Vec<u8>>>(deserializer: D) -> Result<vec::Vec<B>, D::Error>
+    {
+        vec::Vec::<Hex<B>>::deserialize(deserializer).map(|value| value.into_iter().map(|hex| hex.0).collect())
+    }
+}
+
+/// A byte array paired with an index, such as a commitment or a subtree root.
+pub mod indexed_bytes
+{
+    use super::*;
+
+    pub fn serialize<S: Serializer, N: Serialize, B: AsRef<[u8]>>(value: &(N, B), serializer: S) -> Result<S::Ok, S::Error>
+    {
+        (&value.0, Hex(&value.1)).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, N: Deserialize<'de>, B: TryFrom<vec::Vec<u8>>>(deserializer: D) -> Result<(N, B), D::Error>
+    {
+        <(N, Hex<B>)>::deserialize(deserializer).map(|(index, hex)| (index, hex.0))
+    }
+}
+
+/// A sequence of indexed byte arrays.
+pub mod vec_indexed_bytes
+{
+    use super::*;
+
+    pub fn serialize<S: Serializer, N: Serialize, B: AsRef<[u8]>>(value: &vec::Vec<(N, B)>, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        serializer.collect_seq(value.iter().map(|(index, bytes)| (index, Hex(bytes))))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, N: Deserialize<'de>, B: TryFrom<vec::Vec<u8>>>(deserializer: D) -> Result<vec::Vec<(N, B)>, D::Error>
+    {
+        vec::Vec::<(N, Hex<B>)>::deserialize(deserializer).map(|value| value.into_iter().map(|(index, hex)| (index, hex.0)).collect())
+    }
+}
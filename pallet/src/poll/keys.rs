@@ -1,24 +1,69 @@
 use frame_support::pallet_prelude::*;
 use sp_std::vec;
+use ark_bn254::Fr;
+
+use crate::hash::hashbytes_to_fr_checked;
+use crate::poll::HashBytes;
 
 /// A zk verification key.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VerifyKey
 {
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::bytes"))]
     pub alpha_g1: vec::Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::bytes"))]
     pub beta_g2: vec::Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::bytes"))]
     pub gamma_g2: vec::Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::bytes"))]
     pub delta_g2: vec::Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::vec_bytes"))]
     pub gamma_abc_g1: vec::Vec<vec::Vec<u8>>,
 }
 
+impl VerifyKey
+{
+    /// A stable digest of the serialized key fields, used to identify the key off-chain.
+    pub fn fingerprint(&self) -> HashBytes
+    {
+        sp_io::hashing::blake2_256(&self.encode())
+    }
+}
+
 /// A public key used to facillitate secret sharing between participants and coordinators.
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PublicKey 
 {
     /// A 256-bit x-coordinate of the public key.
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::bytes"))]
     pub x: [u8; 32],
 
     /// A 256-bit y-coordinate of the public key.
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::bytes"))]
     pub y: [u8; 32]
 }
+
+impl PublicKey
+{
+    /// Returns true iff both of the coordinates are zero, which is indicative of an uninitialized key.
+    pub fn is_zero(&self) -> bool
+    {
+        self.x == [0u8; 32] && self.y == [0u8; 32]
+    }
+
+    /// Returns true iff the coordinates are canonical field elements which lie on the Baby Jubjub curve
+    /// `a * x^2 + y^2 = 1 + d * x^2 * y^2` used by the circuits.
+    pub fn is_on_curve(&self) -> bool
+    {
+        let (Ok(x), Ok(y)) = (hashbytes_to_fr_checked(&self.x), hashbytes_to_fr_checked(&self.y)) else { return false; };
+
+        let a = Fr::from(168700u64);
+        let d = Fr::from(168696u64);
+        let x2 = x * x;
+        let y2 = y * y;
+
+        a * x2 + y2 == Fr::from(1u64) + d * x2 * y2
+    }
+}
@@ -6,8 +6,11 @@ pub mod state;
 pub mod keys;
 pub mod zeroes;
 
+#[cfg(feature = "serde")]
+pub mod hex;
+
 pub use coordinator::*;
-pub use config::{PollConfiguration};
+pub use config::{PollConfiguration, PollConfigInput, ALLOW_OVERRIDE_CIRCUIT_VERSION};
 pub use poll::*;
 pub use provider::*;
 pub use keys::*;
@@ -15,5 +18,6 @@ pub use state::{
     PollState,
     NewPollState,
     AmortizedIncrementalMerkleTree,
+    MergePhase,
     MerkleTreeError
 };
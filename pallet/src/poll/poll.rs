@@ -1,21 +1,30 @@
 use frame_support::pallet_prelude::*;
 use sp_std::vec;
-use crate::poll::{PollConfiguration, PollState, ProofData};
+use crate::poll::{Commitment, PollConfiguration, PollState, ProofData, PublicKey, VerifyingKeys};
 use crate::hash::poseidon::{HASH_LEN};
 
 pub type BlockNumber = u64;
 pub type CommitmentIndex = u32;
 pub type CommitmentData = HashBytes;
+pub type DepthKeys<T> = BoundedVec<((u8, u8), VerifyingKeys), <T as crate::Config>::MaxDepthKeys>;
 pub type HashBytes = [u8; HASH_LEN];
 pub type Outcome = u128;
 pub type OutcomeIndex = u32;
 pub type PollId = u32;
-pub type PollInteractionData = [[u8; 32]; 10]; 
+pub type PollInteractionBatch<T> = BoundedVec<(PublicKey, PollInteractionData), <T as crate::Config>::MaxIterationDepth>;
+pub type PollInteractionData = PollInteractionMessage<MESSAGE_WIDTH>;
+pub type PollInteractionMessage<const WIDTH: usize> = [[u8; 32]; WIDTH];
+pub type PollMetadata<T> = BoundedVec<u8, <T as crate::Config>::MaxMetadataLen>;
 pub type ProofBatches = vec::Vec<(ProofData, CommitmentData)>;
 pub type VoteOptions<T> = BoundedVec<u128, <T as crate::Config>::MaxVoteOptions>;
 
+/// The number of fields in the messages accepted by the pallet extrinsics.
+pub const MESSAGE_WIDTH: usize = 10;
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
 pub struct Poll<T: crate::Config>
 {
     /// The poll id.
@@ -33,3 +42,90 @@ pub struct Poll<T: crate::Config>
     /// The poll config.
     pub config: PollConfiguration<T>
 }
+
+/// The immutable record of a finalized poll, retained independently of the poll working state for auditing.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct FinalizedPoll<T: crate::Config>
+{
+    /// The poll creator.
+    pub coordinator: T::AccountId,
+
+    /// The public key of the coordinator at finalization, under which the interactions were decrypted.
+    pub coordinator_key: PublicKey,
+
+    /// The number of the block in which the poll was created.
+    pub created_at: BlockNumber,
+
+    /// The poll config.
+    pub config: PollConfiguration<T>,
+
+    /// The full depths of the registration and interaction trees, as supplied to the circuits.
+    pub depths: (u8, u8),
+
+    /// The number of registrations and interactions.
+    pub counts: (u32, u32),
+
+    /// The root of the registration tree.
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::option_bytes"))]
+    pub registration_root: Option<HashBytes>,
+
+    /// The root of the interaction tree.
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::option_bytes"))]
+    pub interaction_root: Option<HashBytes>,
+
+    /// The final proof commitments.
+    pub commitment: Commitment,
+
+    /// The index of the winning vote option.
+    pub outcome: OutcomeIndex,
+
+    /// The verified tally of each vote option, if it was supplied.
+    pub tally_results: Option<vec::Vec<u32>>
+}
+
+impl<T: crate::Config> FinalizedPoll<T>
+{
+    /// Summarizes a poll whose outcome has been verified, or returns None if the poll has no outcome.
+    pub fn new(
+        poll: &Poll<T>,
+        coordinator_key: PublicKey
+    ) -> Option<Self>
+    {
+        Some(FinalizedPoll {
+            coordinator: poll.coordinator.clone(),
+            coordinator_key,
+            created_at: poll.created_at,
+            config: poll.config.clone(),
+            depths: (poll.state.registrations.full_depth, poll.state.interactions.full_depth),
+            counts: (poll.state.registrations.count, poll.state.interactions.count),
+            registration_root: poll.state.registrations.root,
+            interaction_root: poll.state.interactions.root,
+            commitment: poll.state.commitment.clone(),
+            outcome: poll.state.outcome?,
+            tally_results: poll.state.tally_results.clone()
+        })
+    }
+}
+
+/// The phase of a poll, as derived from the current block and the poll state.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum PollStatus
+{
+    /// Participants may register in the poll.
+    Registration,
+
+    /// Participants may interact with the poll.
+    Voting,
+
+    /// The poll has ended and awaits the outcome from its coordinator.
+    Tallying,
+
+    /// The outcome of the poll has been verified.
+    Finalized,
+
+    /// The poll was nullified.
+    Nullified
+}
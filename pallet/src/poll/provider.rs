@@ -1,21 +1,23 @@
 use sp_std::vec;
-use sp_runtime::traits::SaturatedConversion;
 use ark_bn254::{Fr};
 use ark_ff::{PrimeField, BigInteger};
-use crate::hash::{Poseidon, PoseidonHasher};
+use crate::hash::{Poseidon, PoseidonHasher, PoseidonError, hashbytes_to_fr_checked};
 use crate::poll::{
+    ALLOW_OVERRIDE_CIRCUIT_VERSION,
     AmortizedIncrementalMerkleTree, 
     BlockNumber,
     Commitment,
-    Coordinator,
     HashBytes,
     MerkleTreeError,
     OutcomeIndex,
     PollOutcome,
     Poll, 
+    PollStatus,
     PublicKey,
-    PollInteractionData,
+    PollInteractionMessage,
+    VerifyError,
     VerifyKey,
+    VerifyingKeys,
     zeroes::EMPTY_BALLOT_ROOTS
 };
 
@@ -24,25 +26,26 @@ pub trait PollProvider<T: crate::Config>: Sized
     fn verify_outcome(
         self,
         oucome: Option<PollOutcome>
-    ) -> Option<OutcomeIndex>;
+    ) -> Result<Option<OutcomeIndex>, crate::Error<T>>;
 
     fn prepare_public_inputs(
         self,
-        coordinator: Coordinator,
+        public_key: PublicKey,
+        verify_keys: VerifyingKeys,
         new_commitment: HashBytes
-    ) -> Option<(VerifyKey, vec::Vec<Fr>, Commitment)>;
+    ) -> Result<(VerifyKey, vec::Vec<Fr>, Commitment), crate::Error<T>>;
 
     fn register_participant(
         self, 
         public_key: PublicKey, 
         timestamp: u64
-    ) -> Result<(u32, Self), MerkleTreeError>;
+    ) -> Result<(u32, bool, Self), MerkleTreeError>;
 
-    fn consume_interaction(
+    fn consume_interaction<const WIDTH: usize>(
         self,
         public_key: PublicKey,
-        data: PollInteractionData
-    ) -> Result<(u32, Self), MerkleTreeError>;
+        data: PollInteractionMessage<WIDTH>
+    ) -> Result<(u32, bool, Self), MerkleTreeError>;
 
     fn merge_registrations(self) -> Result<Self, MerkleTreeError>;
 
@@ -56,19 +59,31 @@ pub trait PollProvider<T: crate::Config>: Sized
 
     fn is_registration_period(&self) -> bool;
 
+    fn is_registration_settled(&self) -> bool;
+
     fn get_voting_period_end(&self) -> BlockNumber;
 
     fn is_over(&self) -> bool;
 
+    fn blocks_remaining(&self) -> Option<(PollStatus, BlockNumber)>;
+
+    fn status(&self) -> PollStatus;
+
     fn is_fulfilled(&self) -> bool;
 
     fn is_merged(&self) -> bool;
 
     fn is_proven(&self) -> bool;
 
+    fn can_commit_outcome(&self) -> bool;
+
     fn is_nullified(&self) -> bool;
 
+    fn is_dead(&self) -> bool;
+
     fn nullify(self) -> Self;
+
+    fn prune(self) -> Self;
 }
 
 impl<T: crate::Config> PollProvider<T> for Poll<T>
@@ -76,22 +91,19 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
     fn verify_outcome(
         self,
         outcome: Option<PollOutcome>
-    ) -> Option<OutcomeIndex>
+    ) -> Result<Option<OutcomeIndex>, crate::Error<T>>
     {
-        // Ensure that all of the expected proofs have been successfully verified.
-        if !self.is_proven() { return None; }
+        // The outcome may only be verified once all of the expected proofs have been successfully verified.
+        if !self.is_proven() { return Ok(None); }
 
-        let Some(outcome) = outcome else { return None; };
-        let Some(mut hasher) = Poseidon::<Fr>::new_circom(2).ok() else { return None; };
+        let Some(outcome) = outcome else { return Ok(None); };
+        let Some(mut hasher) = Poseidon::<Fr>::new_circom(2).ok() else { Err(crate::Error::<T>::PollOutcomeInvalid)? };
         
-        let mut outcome_index: OutcomeIndex = 0;
-        let mut max_tally_result = 0;
-
         // Verify the tally result for each individual vote option.
         for option_index in 0..self.config.vote_options.len()
         {
-            let Some(tally_result) = outcome.tally_results.get(option_index) else { return None; };
-            let Some(tally_path) = outcome.tally_result_proofs.get(option_index) else { return None; };
+            let Some(tally_result) = outcome.tally_results.get(option_index) else { Err(crate::Error::<T>::PollOutcomeInvalid)? };
+            let Some(tally_path) = outcome.tally_result_proofs.get(option_index) else { Err(crate::Error::<T>::PollOutcomeInvalid)? };
             let mut tally_result_bytes = [0u8; 32];
             tally_result_bytes[28..].copy_from_slice(&tally_result.to_be_bytes());
 
@@ -100,190 +112,182 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
                 option_index as u32,
                 tally_result_bytes,
                 tally_path.clone()
-            ) else { return None; };
+            ) else { Err(crate::Error::<T>::PollOutcomeInvalid)? };
 
             let mut inputs: vec::Vec<Fr> = vec::Vec::<Fr>::new();
             inputs.push(Fr::from_be_bytes_mod_order(&root));
             inputs.push(Fr::from_be_bytes_mod_order(&outcome.tally_result_salt));
-            let Some(hash) = hasher.hash(&inputs).ok() else { return None; };
+            let Some(hash) = hasher.hash(&inputs).ok() else { Err(crate::Error::<T>::PollOutcomeInvalid)? };
 
             let mut inputs: vec::Vec<Fr> = vec::Vec::<Fr>::new();
             inputs.push(Fr::from_be_bytes_mod_order(&hash.into_bigint().to_bytes_be()));
             inputs.push(Fr::from_be_bytes_mod_order(&outcome.spent_votes_hash));
-            let Some(hash) = hasher.hash(&inputs).ok() else { return None; };
+            let Some(hash) = hasher.hash(&inputs).ok() else { Err(crate::Error::<T>::PollOutcomeInvalid)? };
 
-            if hash.into_bigint().to_bytes_be() != self.state.commitment.tally.1 { return None; }
-
-            // Track the vote option with the largest tally.
-            if *tally_result > max_tally_result
-            {
-                outcome_index = option_index as OutcomeIndex;
-                max_tally_result = *tally_result;
-            }
+            if hash.into_bigint().to_bytes_be() != self.state.commitment.tally.1 { Err(crate::Error::<T>::PollOutcomeInvalid)? }
         }
 
         // Verify the total number of votes cast.
         let mut inputs: vec::Vec<Fr> = vec::Vec::<Fr>::new();
         inputs.push(Fr::from_be_bytes_mod_order(&outcome.total_spent));
         inputs.push(Fr::from_be_bytes_mod_order(&outcome.total_spent_salt));
-        let Some(hash) = hasher.hash(&inputs).ok() else { return None; };
+        let Some(hash) = hasher.hash(&inputs).ok() else { Err(crate::Error::<T>::PollOutcomeInvalid)? };
 
         let mut inputs: vec::Vec<Fr> = vec::Vec::<Fr>::new();
         inputs.push(Fr::from_be_bytes_mod_order(&outcome.new_results_commitment));
         inputs.push(Fr::from_be_bytes_mod_order(&hash.into_bigint().to_bytes_be()));
-        let Some(hash) = hasher.hash(&inputs).ok() else { return None; };
+        let Some(hash) = hasher.hash(&inputs).ok() else { Err(crate::Error::<T>::PollOutcomeInvalid)? };
 
-        if hash.into_bigint().to_bytes_be() != self.state.commitment.tally.1 { return None; }
+        if hash.into_bigint().to_bytes_be() != self.state.commitment.tally.1 { Err(crate::Error::<T>::PollOutcomeInvalid)? }
 
-        Some(outcome_index)
+        Ok(Some(tally_argmax(&outcome.tally_results[..self.config.vote_options.len()])))
     }
 
     fn prepare_public_inputs(
         self,
-        coordinator: Coordinator,
+        public_key: PublicKey,
+        verify_keys: VerifyingKeys,
         new_commitment: HashBytes
-    ) -> Option<(VerifyKey, vec::Vec<Fr>, Commitment)>
+    ) -> Result<(VerifyKey, vec::Vec<Fr>, Commitment), crate::Error<T>>
     {
+        // The message processing inputs depend upon the interaction tree root.
+        let Some(root_bytes) = self.state.interactions.root else { Err(crate::Error::<T>::PollStateNotMerged)? };
+
         let verify_key: VerifyKey;
         let mut inputs: vec::Vec<Fr> = vec::Vec::<Fr>::new();
 
-        let message_batch_size: u32 = self.state.interactions.arity.pow(self.config.process_subtree_depth.into()).into();
-        let mut current_batch_index = self.state.interactions.count;
-        if current_batch_index > 0
+        // Return inputs for message processing circuit until every expected process proof has been verified.
+        if self.state.commitment.process.0 < self.state.commitment.expected_process
         {
-            let r = self.state.interactions.count % message_batch_size;
-            if r == 0 { current_batch_index -= message_batch_size; }
-            else { current_batch_index -= r; }
-        }
-        let mut proof_index = self.state.commitment.process.0;
-        let index_offset = proof_index * message_batch_size;
+            verify_key = verify_keys.process;
 
-        // Return inputs for message processing circuit
-        if index_offset <= current_batch_index
-        {
-            verify_key = coordinator.verify_key.process;
+            // The batch size is computed in u32, since e.g. a quinary tree overflows a u8 from a subtree depth of four.
+            let Some(message_batch_size) = u32::from(self.state.interactions.arity)
+                .checked_pow(self.config.process_subtree_depth.into()) else { Err(crate::Error::<T>::MalformedProof { reason: VerifyError::PUBLIC_INPUTS_INVALID })? };
 
-            let Some(mut hasher) = Poseidon::<Fr>::new_circom(2).ok() else { return None; };
-            let coord_pub_key = coordinator.public_key.clone();
-            let coord_pub_key_fr: vec::Vec<Fr> = vec::Vec::from([ coord_pub_key.x, coord_pub_key.y ])
+            // Batches are processed in reverse, starting from the final, possibly partial, batch.
+            let mut current_batch_index = self.state.interactions.count;
+            if current_batch_index > 0
+            {
+                let r = self.state.interactions.count % message_batch_size;
+                let Some(index) = current_batch_index.checked_sub(if r == 0 { message_batch_size } else { r })
+                    else { Err(crate::Error::<T>::MalformedProof { reason: VerifyError::PUBLIC_INPUTS_INVALID })? };
+                current_batch_index = index;
+            }
+            let proof_index = self.state.commitment.process.0;
+            let Some(index_offset) = proof_index.checked_mul(message_batch_size)
+                else { Err(crate::Error::<T>::MalformedProof { reason: VerifyError::PUBLIC_INPUTS_INVALID })? };
+
+            let Some(mut hasher) = Poseidon::<Fr>::new_circom(2).ok() else { Err(crate::Error::<T>::MalformedProof { reason: VerifyError::PUBLIC_INPUTS_INVALID })? };
+            let coord_pub_key_fr: vec::Vec<Fr> = vec::Vec::from([ public_key.x, public_key.y ])
                 .iter()
                 .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
                 .collect();
-            let Some(coord_pub_key_hash) = hasher.hash(&coord_pub_key_fr).ok() else { return None; };
-            let Some(root_bytes) = self.state.interactions.root else { return None; };
-
-            current_batch_index -= index_offset;
-            let mut end_batch_index = current_batch_index + message_batch_size;
-            if end_batch_index > self.state.interactions.count { end_batch_index = self.state.interactions.count; }
+            let Some(coord_pub_key_hash) = hasher.hash(&coord_pub_key_fr).ok() else { Err(crate::Error::<T>::MalformedProof { reason: VerifyError::PUBLIC_INPUTS_INVALID })? };
+
+            let Some(current_batch_index) = current_batch_index.checked_sub(index_offset)
+                else { Err(crate::Error::<T>::MalformedProof { reason: VerifyError::PUBLIC_INPUTS_INVALID })? };
+            let end_batch_index = current_batch_index
+                .saturating_add(message_batch_size)
+                .min(self.state.interactions.count);
+
+            // The root and commitments must be canonical field elements, a stored value which is not indicates corrupted state.
+            let (Ok(root), Ok(current_commitment), Ok(next_commitment)) = (
+                hashbytes_to_fr_checked(&root_bytes),
+                hashbytes_to_fr_checked(&self.state.commitment.process.1),
+                hashbytes_to_fr_checked(&new_commitment)
+            ) else { Err(crate::Error::<T>::MalformedProof { reason: VerifyError::PUBLIC_INPUTS_INVALID })? };
             
+            // The inputs must be supplied in the order in which the message processing circuit declares its public signals.
             inputs.push(Fr::from(self.state.registrations.count + 1));
             inputs.push(Fr::from(self.get_voting_period_end()));
-            inputs.push(Fr::from_be_bytes_mod_order(&root_bytes));
+            inputs.push(root);
             inputs.push(Fr::from(self.state.registrations.depth));
             inputs.push(Fr::from(end_batch_index));
             inputs.push(Fr::from(current_batch_index));
             inputs.push(coord_pub_key_hash);
-            inputs.push(Fr::from_be_bytes_mod_order(&self.state.commitment.process.1));
-            inputs.push(Fr::from_be_bytes_mod_order(&new_commitment));
+            inputs.push(current_commitment);
+            inputs.push(next_commitment);
+
+            // Circuit revisions which declare the flag are supplied it regardless of its value, such that the input
+            // vector has a fixed length for a given verify key.
+            if self.config.circuit_version >= ALLOW_OVERRIDE_CIRCUIT_VERSION { inputs.push(Fr::from(self.config.allow_override)); }
 
             let mut commitment = self.state.commitment.clone();
             commitment.process = (proof_index + 1, new_commitment);
     
-            Some((verify_key, inputs, commitment))
+            Ok((verify_key, inputs, commitment))
         }
 
         // Return inputs for tally circuit
         else
         {
-            proof_index = self.state.commitment.tally.0;
-            verify_key = coordinator.verify_key.tally;
-
-            let batch_size: u32 = self.state.registrations.arity.pow(self.config.tally_subtree_depth.into()).into();
-            let current_batch_index = proof_index * batch_size;
-            if current_batch_index >= self.state.registrations.count + 1 { return None; }
-
-            inputs.push(Fr::from_be_bytes_mod_order(&self.state.commitment.process.1));
-            inputs.push(Fr::from_be_bytes_mod_order(&self.state.commitment.tally.1));
-            inputs.push(Fr::from_be_bytes_mod_order(&new_commitment));
+            // Every expected proof has already been verified.
+            if self.state.commitment.tally.0 >= self.state.commitment.expected_tally { Err(crate::Error::<T>::ExcessProofBatch)? }
+
+            let proof_index = self.state.commitment.tally.0;
+            verify_key = verify_keys.tally;
+
+            let Some(current_batch_index) = u32::from(self.state.registrations.arity)
+                .checked_pow(self.config.tally_subtree_depth.into())
+                .and_then(|batch_size| proof_index.checked_mul(batch_size)) else { Err(crate::Error::<T>::MalformedProof { reason: VerifyError::PUBLIC_INPUTS_INVALID })? };
+            if current_batch_index >= self.state.registrations.count + 1 { Err(crate::Error::<T>::MalformedProof { reason: VerifyError::PUBLIC_INPUTS_INVALID })? }
+
+            // The tally chain is seeded from the final process commitment, which is supplied to every tally proof, whereas the
+            // current tally commitment of the first tally proof is zero.
+            let (Ok(process_commitment), Ok(current_commitment), Ok(next_commitment)) = (
+                hashbytes_to_fr_checked(&self.state.commitment.process.1),
+                hashbytes_to_fr_checked(&self.state.commitment.tally.1),
+                hashbytes_to_fr_checked(&new_commitment)
+            ) else { Err(crate::Error::<T>::MalformedProof { reason: VerifyError::PUBLIC_INPUTS_INVALID })? };
+
+            inputs.push(process_commitment);
+            inputs.push(current_commitment);
+            inputs.push(next_commitment);
             inputs.push(Fr::from(current_batch_index));
             inputs.push(Fr::from(self.state.registrations.count + 1));
 
             let mut commitment = self.state.commitment.clone();
             commitment.tally = (proof_index + 1, new_commitment);
 
-            Some((verify_key, inputs, commitment))
+            Ok((verify_key, inputs, commitment))
         }
     }
 
+    /// Inserts a registration leaf. The registration tree is frozen once the registration period has ended, such that 
+    /// every interaction is consumed against a fixed set of registrations.
     fn register_participant(
         mut self, 
         public_key: PublicKey,
         timestamp: u64
-    ) -> Result<(u32, Self), MerkleTreeError>
+    ) -> Result<(u32, bool, Self), MerkleTreeError>
     {
-        let Some(mut hasher) = Poseidon::<Fr>::new_circom(4).ok() else { Err(MerkleTreeError::HashFailed)? };
+        if !self.is_registration_period() { Err(MerkleTreeError::TreeFrozen)? }
 
-        let mut inputs: vec::Vec<Fr> = vec::Vec::from([ public_key.x, public_key.y ])
-            .iter()
-            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-            .collect();
-        inputs.push(Fr::from(1));
-        inputs.push(Fr::from(timestamp));
-
-        let Some(result) = hasher.hash(&inputs).ok() else { Err(MerkleTreeError::HashFailed)? };
-        let bytes = result.into_bigint().to_bytes_be();
-        let mut leaf = [0u8; 32];
-        leaf[..bytes.len()].copy_from_slice(&bytes);
+        let Some(leaf) = registration_leaf(&public_key, 1, timestamp).ok() else { Err(MerkleTreeError::HashFailed)? };
 
-        self.state.registrations = self.state.registrations.insert(leaf)?;
+        let (registrations, filled) = self.state.registrations.insert_checked(leaf)?;
+        self.state.registrations = registrations;
 
-        Ok((self.state.registrations.count, self))
+        Ok((self.state.registrations.count, filled, self))
     }
 
-    fn consume_interaction(
+    /// Inserts an interaction leaf. Only the interaction tree is modified, and only from the end of the registration
+    /// period up to and including the final block of the voting period, by which point the registration tree is frozen.
+    fn consume_interaction<const WIDTH: usize>(
         mut self, 
         public_key: PublicKey,
-        data: PollInteractionData
-    ) -> Result<(u32, Self), MerkleTreeError>
+        data: PollInteractionMessage<WIDTH>
+    ) -> Result<(u32, bool, Self), MerkleTreeError>
     {
-        let Some(mut hash4) = Poseidon::<Fr>::new_circom(4).ok() else { Err(MerkleTreeError::HashFailed)? };
-        let Some(mut hash5) = Poseidon::<Fr>::new_circom(5).ok() else { Err(MerkleTreeError::HashFailed)? };
+        if self.is_registration_period() || self.is_over() { Err(MerkleTreeError::TreeFrozen)? }
 
-        let left_inputs: vec::Vec<Fr> = vec::Vec::from([ data[0], data[1], data[2], data[3], data[4] ])
-            .iter()
-            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-            .collect();
-
-        let right_inputs: vec::Vec<Fr> = vec::Vec::from([ data[5], data[6], data[7], data[8], data[9] ])
-            .iter()
-            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-            .collect();
-
-        let Some(left) = hash5.hash(&left_inputs).ok() else { Err(MerkleTreeError::HashFailed)? };
-        let Some(right) = hash5.hash(&right_inputs).ok() else { Err(MerkleTreeError::HashFailed)? };
+        let Some(leaf) = interaction_leaf(&public_key, &data).ok() else { Err(MerkleTreeError::HashFailed)? };
 
-        let left_bytes = left.into_bigint().to_bytes_be();
-        let right_bytes = right.into_bigint().to_bytes_be();
+        let (interactions, filled) = self.state.interactions.insert_checked(leaf)?;
+        self.state.interactions = interactions;
 
-        let inputs: vec::Vec<Fr> = vec::Vec::from([
-            left_bytes,
-            right_bytes,
-            vec::Vec::from(public_key.x),
-            vec::Vec::from(public_key.y)
-        ])
-            .iter()
-            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-            .collect();
-
-        let Some(result) = hash4.hash(&inputs).ok() else { Err(MerkleTreeError::HashFailed)? };
-
-        let bytes = result.into_bigint().to_bytes_be();
-        let mut leaf = [0u8; 32];
-        leaf[..bytes.len()].copy_from_slice(&bytes);
-
-        self.state.interactions = self.state.interactions.insert(leaf)?;
-
-        Ok((self.state.interactions.count, self))
+        Ok((self.state.interactions.count, filled, self))
     }
 
     fn merge_registrations(
@@ -293,19 +297,8 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
         self.state.registrations = self.state.registrations.merge(false)?;
 
         let Some(root) = self.state.registrations.root else { Err(MerkleTreeError::MergeFailed)? };
-        let Some(mut hasher) = Poseidon::<Fr>::new_circom(3).ok() else { Err(MerkleTreeError::HashFailed)? };
-
-        let inputs: vec::Vec<Fr> = vec::Vec::from([ root, EMPTY_BALLOT_ROOTS[1], [0u8;32] ])
-            .iter()
-            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-            .collect();
 
-        let Some(result) = hasher.hash(&inputs).ok() else { Err(MerkleTreeError::HashFailed)? };
-        let bytes = result.into_bigint().to_bytes_be();
-        let mut commitment = [0u8; 32];
-        commitment[..bytes.len()].copy_from_slice(&bytes);
-
-        self.state.commitment.process = (0, commitment);
+        self.state.commitment.process = (0, initial_process_commitment(root)?);
 
         Ok(self)
     }
@@ -316,12 +309,18 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
     {
         self.state.interactions = self.state.interactions.merge(true)?;
 
-        let process_batch_size: u32 = self.state.interactions.arity.pow(self.config.process_subtree_depth.into()).into();
-        let process_extra_batch = if (self.state.interactions.count % process_batch_size) > 0 { 1 } else { 0 };
-        self.state.commitment.expected_process = (self.state.interactions.count / process_batch_size) + process_extra_batch;
+        self.state.commitment.expected_process = expected_batches(
+            self.state.interactions.count,
+            self.config.process_subtree_depth,
+            self.state.interactions.arity
+        );
 
-        let tally_batch_size: u32 = self.state.registrations.arity.pow(self.config.tally_subtree_depth.into()).into();
-        self.state.commitment.expected_tally = 1 + (self.state.registrations.count / tally_batch_size);
+        // The tally circuit also processes the zero leaf which is reserved at the start of the registration tree.
+        self.state.commitment.expected_tally = expected_batches(
+            self.state.registrations.count.saturating_add(1),
+            self.config.tally_subtree_depth,
+            self.state.registrations.arity
+        );
 
         Ok(self)
     }
@@ -339,7 +338,7 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
     /// Returns true iff poll is not None and `now` preceeds the end time of the poll.
     fn is_voting_period(&self) -> bool
     {
-        let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+        let now = current_block::<T>().unwrap_or(BlockNumber::MAX);
         let voting_period_start = self.created_at + self.config.signup_period;
         let voting_period_end = voting_period_start + self.config.voting_period;
         now >= voting_period_start && now < voting_period_end
@@ -348,10 +347,18 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
     /// Returns true iff poll is currently within the registration period.
 	fn is_registration_period(&self) -> bool
 	{
-		let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+		let now = current_block::<T>().unwrap_or(BlockNumber::MAX);
 		now >= self.created_at && now < self.created_at + self.config.signup_period
 	}
 
+    /// Returns true iff at least one block has elapsed since the final block of the registration period, such that 
+    /// every registration is settled before the registration tree may be merged.
+    fn is_registration_settled(&self) -> bool
+    {
+		let now = current_block::<T>().unwrap_or(BlockNumber::MAX);
+		now > self.created_at + self.config.signup_period
+    }
+
     fn get_voting_period_end(&self) -> BlockNumber
     {
         self.created_at + self.config.signup_period + self.config.voting_period
@@ -360,10 +367,35 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
     /// Returns true iff poll has ended.
     fn is_over(&self) -> bool
     {
-		let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+		let now = current_block::<T>().unwrap_or(BlockNumber::MAX);
 		now > self.get_voting_period_end()
     }
 
+    /// Returns the current phase of the poll and the number of blocks remaining in it, or None if the poll has ended.
+    fn blocks_remaining(&self) -> Option<(PollStatus, BlockNumber)>
+    {
+        let now = current_block::<T>().unwrap_or(BlockNumber::MAX);
+        if self.is_registration_period()
+        {
+            return Some((PollStatus::Registration, self.created_at + self.config.signup_period - now));
+        }
+        if self.is_over() { return None; }
+
+        // Interactions are accepted up to and including the final block of the voting period.
+        Some((PollStatus::Voting, self.get_voting_period_end() + 1 - now))
+    }
+
+    /// Returns the current phase of the poll.
+    fn status(&self) -> PollStatus
+    {
+        if self.is_nullified() { return PollStatus::Nullified; }
+        if self.state.outcome.is_some() { return PollStatus::Finalized; }
+        if self.is_registration_period() { return PollStatus::Registration; }
+        if self.is_over() { return PollStatus::Tallying; }
+
+        PollStatus::Voting
+    }
+
     /// Returns true iff poll outcome has been committed to state, or the poll is dead.
     fn is_fulfilled(&self) -> bool
     {
@@ -381,18 +413,160 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
             (self.state.commitment.tally.0 == self.state.commitment.expected_tally)
     }
 
+    /// Returns true iff the poll is over, its state trees have been merged, and its outcome is yet to be committed.
+    fn can_commit_outcome(&self) -> bool
+    {
+        self.is_over() && self.is_merged() && !self.is_fulfilled()
+    }
+
     fn is_nullified(&self) -> bool
     {
         self.state.tombstone
     }
 
+    /// Returns true iff the poll may no longer be mutated.
+    fn is_dead(&self) -> bool
+    {
+        self.is_nullified()
+    }
+
+    /// Marks the poll with a tombstone. A nullified poll is never processed, so the partially merged subtrees of its
+    /// state trees are discarded immediately, as per `prune`.
     fn nullify(mut self) -> Self
     {
         self.state.tombstone = true;
+        self.prune()
+    }
+
+    /// Discards the partially merged subtrees of the state trees, retaining their roots.
+    fn prune(mut self) -> Self
+    {
+        self.state.registrations.hashes = vec::Vec::new();
+        self.state.interactions.hashes = vec::Vec::new();
         self
     }
 }
 
+/// Returns the current block number, or None if it cannot be represented as a `BlockNumber`. Blocks beyond the
+/// representable range succeed the end of every poll.
+pub fn current_block<T: crate::Config>() -> Option<BlockNumber>
+{
+    <frame_system::Pallet<T>>::block_number().try_into().ok()
+}
+
+/// Returns the index of the vote option with the largest tally, preferring the lowest index amongst ties.
+pub fn tally_argmax(
+    tally_results: &[u32]
+) -> OutcomeIndex
+{
+    let mut outcome_index: OutcomeIndex = 0;
+    let mut max_tally_result = 0;
+
+    for (option_index, tally_result) in tally_results.iter().enumerate()
+    {
+        if *tally_result > max_tally_result
+        {
+            outcome_index = option_index as OutcomeIndex;
+            max_tally_result = *tally_result;
+        }
+    }
+
+    outcome_index
+}
+
+/// Computes the registration leaf `hash4(x, y, voice_credits, block)` of a participant. Together with the
+/// `ParticipantRegistered` event data this permits an off-chain observer to rebuild the registration tree.
+pub fn registration_leaf(
+    public_key: &PublicKey,
+    voice_credits: u64,
+    block: u64
+) -> Result<HashBytes, PoseidonError>
+{
+    let mut hasher = Poseidon::<Fr>::new_circom(4)?;
+
+    let inputs = [
+        Fr::from_be_bytes_mod_order(&public_key.x),
+        Fr::from_be_bytes_mod_order(&public_key.y),
+        Fr::from(voice_credits),
+        Fr::from(block)
+    ];
+
+    let result = hasher.hash(&inputs)?;
+    let bytes = result.into_bigint().to_bytes_be();
+    let mut leaf = [0u8; 32];
+    leaf[..bytes.len()].copy_from_slice(&bytes);
+
+    Ok(leaf)
+}
+
+/// Computes the interaction tree leaf of a message of arbitrary width, as recoverable from the `PollInteraction` event
+/// data alone. The message fields are folded in chunks of up to five fields, and the chunk digests are hashed together
+/// with the ephemeral public key. For the default ten field message this is `hash4(hash5(data[0..5]), hash5(data[5..10]), x, y)`.
+pub fn interaction_leaf(
+    public_key: &PublicKey,
+    data: &[HashBytes]
+) -> Result<HashBytes, PoseidonError>
+{
+    const CHUNK_WIDTH: usize = 5;
+
+    let mut inputs: vec::Vec<Fr> = vec::Vec::new();
+    for chunk in data.chunks(CHUNK_WIDTH)
+    {
+        let mut hasher = Poseidon::<Fr>::new_circom(chunk.len())?;
+        let chunk_inputs: vec::Vec<Fr> = chunk
+            .iter()
+            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
+            .collect();
+
+        inputs.push(hasher.hash(&chunk_inputs)?);
+    }
+    inputs.push(Fr::from_be_bytes_mod_order(&public_key.x));
+    inputs.push(Fr::from_be_bytes_mod_order(&public_key.y));
+
+    let mut hasher = Poseidon::<Fr>::new_circom(inputs.len())?;
+    let result = hasher.hash(&inputs)?;
+
+    let bytes = result.into_bigint().to_bytes_be();
+    let mut leaf = [0u8; 32];
+    leaf[..bytes.len()].copy_from_slice(&bytes);
+
+    Ok(leaf)
+}
+
+/// Returns the number of proof batches required to process `count` leaves, where each batch spans the leaves of a
+/// subtree of the given depth and arity.
+pub fn expected_batches(
+    count: u32,
+    subtree_depth: u8,
+    arity: u8
+) -> u32
+{
+    let batch_size = u32::from(arity).saturating_pow(subtree_depth.into()).max(1);
+    let extra_batch = if (count % batch_size) > 0 { 1 } else { 0 };
+
+    (count / batch_size) + extra_batch
+}
+
+/// Computes the commitment which seeds the process chain from the root of the registration tree.
+pub fn initial_process_commitment(
+    registration_root: HashBytes
+) -> Result<HashBytes, MerkleTreeError>
+{
+    let Some(mut hasher) = Poseidon::<Fr>::new_circom(3).ok() else { Err(MerkleTreeError::HashFailed)? };
+
+    let inputs: vec::Vec<Fr> = vec::Vec::from([ registration_root, EMPTY_BALLOT_ROOTS[1], [0u8;32] ])
+        .iter()
+        .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
+        .collect();
+
+    let Some(result) = hasher.hash(&inputs).ok() else { Err(MerkleTreeError::HashFailed)? };
+    let bytes = result.into_bigint().to_bytes_be();
+    let mut commitment = [0u8; 32];
+    commitment[..bytes.len()].copy_from_slice(&bytes);
+
+    Ok(commitment)
+}
+
 fn compute_merkle_root_from_path(
     depth: u8,
     index: u32,
@@ -416,7 +590,7 @@ fn compute_merkle_root_from_path(
             else
             {
                 let k = if j > position { j - 1 } else { j };
-                level[j as usize] = path[i as usize][k as usize];
+                level[j as usize] = *path.get(i as usize)?.get(k as usize)?;
             }
         }
 
@@ -8,9 +8,10 @@ use crate::poll::{
     HashBytes,
     zeroes::get_merkle_zeroes
 };
-use crate::hash::{Poseidon, PoseidonHasher, PoseidonError};
+use crate::hash::{Poseidon, PoseidonHasher, PoseidonError, MAX_X5_LEN};
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PollState
 {
     /// The merkle tree of registration data.
@@ -25,16 +26,45 @@ pub struct PollState
     /// The final result of the poll.
     pub outcome: Option<OutcomeIndex>,
 
+    /// The verified tally of each vote option, recorded alongside the outcome.
+    pub tally_results: Option<vec::Vec<u32>>,
+
     /// Whether the poll was nullified
     pub tombstone: bool
 }
 
+/// The state tree merged by a call to `merge_poll_state`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum MergePhase
+{
+    /// The registration tree, merged once the signup period has elapsed.
+    Registration,
+
+    /// The interaction tree, merged once the voting period has elapsed.
+    Interaction
+}
+
 pub trait NewPollState
 {
     fn new(
         registration_depth: u8,
         interaction_depth: u8
-    ) -> Self;
+    ) -> Result<Self, MerkleTreeError>;
+}
+
+/// The arity of the registration tree.
+const REGISTRATION_ARITY: u8 = 2;
+
+/// The arity of the interaction tree.
+const INTERACTION_ARITY: u8 = 5;
+
+impl PollState
+{
+    /// Returns an upper bound on the number of hashes computed by a merge of either of the state trees.
+    pub fn max_merge_cost() -> u32
+    {
+        PollStateTree::max_merge_cost(REGISTRATION_ARITY).max(PollStateTree::max_merge_cost(INTERACTION_ARITY))
+    }
 }
 
 impl NewPollState for PollState
@@ -42,19 +72,19 @@ impl NewPollState for PollState
     fn new(
         registration_depth: u8,
         interaction_depth: u8
-    ) -> PollState
+    ) -> Result<PollState, MerkleTreeError>
     {
-        PollState {
+        Ok(PollState {
             registrations: PollStateTree::new(
-                2,
+                REGISTRATION_ARITY,
                 registration_depth,
-                Some((0, get_merkle_zeroes(2)[0]))
-            ),
+                Some((0, get_merkle_zeroes(REGISTRATION_ARITY)[0]))
+            )?,
             interactions: PollStateTree::new(
-                5,
+                INTERACTION_ARITY,
                 interaction_depth,
                 None
-            ),
+            )?,
             commitment: Commitment {
                 process: (0, [0; 32]),
                 tally: (0, [0; 32]),
@@ -62,12 +92,14 @@ impl NewPollState for PollState
                 expected_tally: 0
             },
             outcome: None,
+            tally_results: None,
             tombstone: false
-        }
+        })
     }
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PollStateTree
 {
     /// The true depth of the tree (i.e., consisting of non-zero leaves).
@@ -76,6 +108,9 @@ pub struct PollStateTree
     /// The maximal depth of the tree.
     pub full_depth: u8,
 
+    /// The depth to which the tree may grow once it is full. Equal to `full_depth` if the tree may not grow.
+    pub max_depth: u8,
+
     /// The immutable arity of the tree.
     pub arity: u8,
 
@@ -83,24 +118,123 @@ pub struct PollStateTree
     pub count: u32,
 
     /// The (depth, hash) pairs of the incrementally merged subtrees.
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::vec_indexed_bytes"))]
     pub hashes: vec::Vec<(u8, HashBytes)>,
 
     /// The root of the tree of maximal depth which contains the
     /// leaves of `hashes` and zeros elsewhere.
-    pub root: Option<HashBytes>
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::option_bytes"))]
+    pub root: Option<HashBytes>,
+
+    /// The poseidon domain tag used to compute subtree roots, including the zero values used
+    /// to pad the tree during a merge.
+    #[cfg_attr(feature = "serde", serde(with = "crate::poll::hex::bytes"))]
+    pub domain_tag: HashBytes
+}
+
+impl PollStateTree
+{
+    /// Sets the domain tag used to compute subtree roots.
+    pub fn with_domain_tag(
+        mut self,
+        domain_tag: HashBytes
+    ) -> Self
+    {
+        self.domain_tag = domain_tag;
+        self
+    }
+
+    /// Permits the tree to grow beyond its initial depth, up to `max_depth`, rejecting a depth beyond the zero table.
+    pub fn with_max_depth(
+        mut self,
+        max_depth: u8
+    ) -> Result<Self, MerkleTreeError>
+    {
+        if usize::from(max_depth) >= get_merkle_zeroes(self.arity).len() { Err(MerkleTreeError::TreeParametersInvalid)? }
+
+        self.max_depth = max_depth.max(self.full_depth);
+        Ok(self)
+    }
+
+    /// Returns an upper bound on `pending_merge_cost` for any tree of the given arity. The depth of the tree is bounded
+    /// by its zero table, and a merge of a tree of depth `d` computes at most `d + 1` rounds, alongside the `d` zero
+    /// values derived for a tagged tree.
+    pub fn max_merge_cost(
+        arity: u8
+    ) -> u32
+    {
+        let max_depth = get_merkle_zeroes(arity).len().saturating_sub(1) as u32;
+        2 * max_depth + 1
+    }
+
+    /// Returns the root of an empty subtree at each depth up to `full_depth`. Trees with the default domain tag use
+    /// the precomputed zeroes, whereas tagged trees derive them from the zero leaf under their own tag, such that
+    /// the padding of a merge is hashed in the same domain as the rest of the tree.
+    fn zeroes(
+        &self
+    ) -> Result<vec::Vec<HashBytes>, MerkleTreeError>
+    {
+        let zeroes = get_merkle_zeroes(self.arity);
+        if self.domain_tag == [0u8; 32] { return Ok(zeroes.to_vec()); }
+
+        let mut tagged = vec::Vec::from([ zeroes[0] ]);
+        for _ in 0..self.full_depth
+        {
+            let Some(&zero) = tagged.last() else { Err(MerkleTreeError::MergeFailed)? };
+            let subtree: vec::Vec<HashBytes> = (0..self.arity).map(|_| zero).collect();
+            let Some(hash) = self.hash(subtree).ok() else { Err(MerkleTreeError::HashFailed)? };
+            tagged.push(hash);
+        }
+
+        Ok(tagged)
+    }
 }
 
 #[derive(Debug)]
 pub enum MerkleTreeError
 {
-    /// The tree is full and cannot be inserted.
+    /// The tree is full and cannot be inserted. Reported as [`MerkleTreeError::TREE_ALREADY_FULL`].
     TreeAlreadyFull,
-    /// The tree has already been merged.
+    /// The tree has already been merged. Reported as [`MerkleTreeError::TREE_ALREADY_MERGED`].
     TreeAlreadyMerged,
-    /// The hash function did not succeed.
+    /// The hash function did not succeed. Reported as [`MerkleTreeError::HASH_FAILED`].
     HashFailed,
-    /// The merge operation failed.
-    MergeFailed
+    /// The merge operation failed. Reported as [`MerkleTreeError::MERGE_FAILED`].
+    MergeFailed,
+    /// A subtree awaiting merge holds more nodes than the tree arity permits. Reported as [`MerkleTreeError::SUBTREE_OVERSIZED`].
+    SubtreeOversized,
+    /// The tree no longer accepts insertions. Reported as [`MerkleTreeError::TREE_FROZEN`].
+    TreeFrozen,
+    /// The tree was constructed with an arity below two or beyond the Poseidon width, or a depth of zero or beyond the zero table. Reported as [`MerkleTreeError::TREE_PARAMETERS_INVALID`].
+    TreeParametersInvalid
+}
+
+/// The `reason` codes carried by the `PollMergeFailed`, `PollRegistrationFailed` and `PollInteractionFailed` pallet errors.
+impl MerkleTreeError
+{
+    pub const TREE_ALREADY_FULL: u8 = 1;
+    pub const TREE_ALREADY_MERGED: u8 = 2;
+    pub const HASH_FAILED: u8 = 3;
+    pub const MERGE_FAILED: u8 = 4;
+    pub const SUBTREE_OVERSIZED: u8 = 5;
+    pub const TREE_FROZEN: u8 = 6;
+    pub const TREE_PARAMETERS_INVALID: u8 = 7;
+
+    /// Recovers the error from a `reason` code, if the code is known.
+    pub fn from_code(code: u8) -> Option<Self>
+    {
+        match code
+        {
+            Self::TREE_ALREADY_FULL => Some(MerkleTreeError::TreeAlreadyFull),
+            Self::TREE_ALREADY_MERGED => Some(MerkleTreeError::TreeAlreadyMerged),
+            Self::HASH_FAILED => Some(MerkleTreeError::HashFailed),
+            Self::MERGE_FAILED => Some(MerkleTreeError::MergeFailed),
+            Self::SUBTREE_OVERSIZED => Some(MerkleTreeError::SubtreeOversized),
+            Self::TREE_FROZEN => Some(MerkleTreeError::TreeFrozen),
+            Self::TREE_PARAMETERS_INVALID => Some(MerkleTreeError::TreeParametersInvalid),
+            _ => None
+        }
+    }
 }
 
 impl From<MerkleTreeError> for u8
@@ -109,10 +243,13 @@ impl From<MerkleTreeError> for u8
     {
         match error
         {
-            MerkleTreeError::TreeAlreadyFull => 1,
-            MerkleTreeError::TreeAlreadyMerged => 2,
-            MerkleTreeError::HashFailed => 3,
-            MerkleTreeError::MergeFailed => 4,
+            MerkleTreeError::TreeAlreadyFull => MerkleTreeError::TREE_ALREADY_FULL,
+            MerkleTreeError::TreeAlreadyMerged => MerkleTreeError::TREE_ALREADY_MERGED,
+            MerkleTreeError::HashFailed => MerkleTreeError::HASH_FAILED,
+            MerkleTreeError::MergeFailed => MerkleTreeError::MERGE_FAILED,
+            MerkleTreeError::SubtreeOversized => MerkleTreeError::SUBTREE_OVERSIZED,
+            MerkleTreeError::TreeFrozen => MerkleTreeError::TREE_FROZEN,
+            MerkleTreeError::TreeParametersInvalid => MerkleTreeError::TREE_PARAMETERS_INVALID,
         }
     }
 }
@@ -122,17 +259,23 @@ pub trait AmortizedIncrementalMerkleTree: Sized
     /// The error type for the hash function.
     type HashError;
 
-    /// Create a new tree.
-    fn new(arity: u8, full_depth: u8, zero_hash: Option<(u8, HashBytes)>) -> Self;
+    /// Create a new tree, rejecting an arity below two, or a depth of zero or beyond the zero table.
+    fn new(arity: u8, full_depth: u8, zero_hash: Option<(u8, HashBytes)>) -> Result<Self, MerkleTreeError>;
 
     /// Inserts a new leaf into the tree.
     fn insert(self, data: HashBytes) -> Result<Self, MerkleTreeError>;
 
+    /// Inserts a new leaf into the tree, additionally reporting whether the insertion filled the tree.
+    fn insert_checked(self, data: HashBytes) -> Result<(Self, bool), MerkleTreeError>;
+
     /// Compute the root of the tree.
     fn merge(self, to_depth: bool) -> Result<Self, MerkleTreeError>;
 
+    /// The number of hashes a call to `merge` would compute.
+    fn pending_merge_cost(&self, to_depth: bool) -> u32;
+
     /// Hash function used to compute roots.
-    fn hash(inputs: vec::Vec<HashBytes>) -> Result<HashBytes, Self::HashError>;
+    fn hash(&self, inputs: vec::Vec<HashBytes>) -> Result<HashBytes, Self::HashError>;
 }
 
 impl AmortizedIncrementalMerkleTree for PollStateTree
@@ -143,17 +286,26 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
         arity: u8,
         full_depth: u8,
         zero_hash: Option<(u8, HashBytes)>
-    ) -> PollStateTree
+    ) -> Result<PollStateTree, MerkleTreeError>
     {
-        if let Some(hash) = zero_hash
+        // A tree of arity below two never collapses to a root, and a tree of depth zero holds a single leaf. Subtrees are
+        // hashed with a single Poseidon permutation, so `arity + 1` must be within the circom Poseidon width limit.
+        if arity < 2 || usize::from(arity) >= MAX_X5_LEN || full_depth == 0 { Err(MerkleTreeError::TreeParametersInvalid)? }
+
+        // Merging pads each subtree with the zero value of its depth, so the tree may not outgrow its zero table.
+        if usize::from(full_depth) >= get_merkle_zeroes(arity).len() { Err(MerkleTreeError::TreeParametersInvalid)? }
+
+        let tree = if let Some(hash) = zero_hash
         {
             PollStateTree {
                 arity,
                 full_depth,
+                max_depth: full_depth,
                 depth: 0,
                 count: 0,
                 hashes: vec::Vec::<(u8, HashBytes)>::from([ hash ]),
-                root: None
+                root: None,
+                domain_tag: [0u8; 32]
             }
         }
         else
@@ -161,12 +313,16 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
             PollStateTree {
                 arity,
                 full_depth,
+                max_depth: full_depth,
                 depth: 0,
                 count: 0,
                 hashes: vec::Vec::<(u8, HashBytes)>::new(),
-                root: None
+                root: None,
+                domain_tag: [0u8; 32]
             }
-        }
+        };
+
+        Ok(tree)
     }
 
     /// Consumes a new leaf and produces the resultant partially merged merkle tree.
@@ -174,9 +330,22 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
     /// -`leaf`: A new right-most leaf to insert into the tree.
     ///
     fn insert(
-        mut self,
+        self,
         leaf: HashBytes
     ) -> Result<Self, MerkleTreeError>
+    {
+        self.insert_checked(leaf).map(|(tree, _)| tree)
+    }
+
+    /// Consumes a new leaf and produces the resultant partially merged merkle tree, alongside
+    /// whether the tree was filled by the insertion, after which no further leaves are accepted.
+    ///
+    /// -`leaf`: A new right-most leaf to insert into the tree.
+    ///
+    fn insert_checked(
+        mut self,
+        leaf: HashBytes
+    ) -> Result<(Self, bool), MerkleTreeError>
     {
         // Ensure that the tree is not full (or merged).
         if self.root != None { Err(MerkleTreeError::TreeAlreadyFull)? }
@@ -203,7 +372,7 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
                     .map(|&(_, hash)| hash)
                     .collect();
 
-                let Some(hash) = Self::hash(leaves).ok() else { Err(MerkleTreeError::HashFailed)? };
+                let Some(hash) = self.hash(leaves).ok() else { Err(MerkleTreeError::HashFailed)? };
 
                 self.hashes.truncate(size - arity);
                 self.hashes.push((depth + 1, hash));
@@ -214,14 +383,20 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
             else { break; }
         }
 
-        // If tree is full update the `root` property.
+        // If tree is full either grow the tree by a level, deferring the zero padding to the merge, or update the `root` property.
         if self.hashes.len() == 1 && self.hashes[0].0 == self.full_depth
         {
-            self.root = Some(self.hashes[0].1);
-            self.hashes.truncate(0);
+            if self.full_depth < self.max_depth { self.full_depth += 1; }
+            else
+            {
+                self.root = Some(self.hashes[0].1);
+                self.hashes.truncate(0);
+            }
         }
 
-        Ok(self)
+        let filled = self.root.is_some();
+
+        Ok((self, filled))
     }
 
     /// Obtain the root of the tree, wherein the remaining leaves take on zero values.
@@ -235,8 +410,11 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
         // Ensure the tree is not already merged.
         if self.root != None { Err(MerkleTreeError::TreeAlreadyMerged)? }
 
-        let zeroes = get_merkle_zeroes(self.arity);
+        let zeroes = self.zeroes()?;
         let arity: usize = self.arity.into();
+
+        // Each round raises the depth of the right-most node, so a well formed tree collapses within `full_depth + 1` rounds.
+        let mut rounds: u16 = 0;
         loop
         {
             let last = match self.hashes.last()
@@ -250,6 +428,9 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
             // Break as soon as the first full subroot has been computed.
             if self.hashes.len() == 1 && (!to_depth || depth == self.full_depth) {break; }
 
+            rounds += 1;
+            if rounds > u16::from(self.full_depth) + 1 { Err(MerkleTreeError::MergeFailed)? }
+
             let mut subtree: vec::Vec<_> = self.hashes
                 .iter()
                 .rev()
@@ -261,11 +442,14 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
             // We built the subtree in reverse order, so restore the original order.
             subtree.reverse();
 
+            // A well formed tree never accumulates more than `arity` nodes at a given depth.
             let size = subtree.len();
-            let zero = zeroes[depth as usize];
-            if arity >= size { subtree.extend((0..(arity - size)).map(|_| zero)); }
+            if size > arity { Err(MerkleTreeError::SubtreeOversized)? }
+
+            let Some(&zero) = zeroes.get(depth as usize) else { Err(MerkleTreeError::MergeFailed)? };
+            subtree.extend((0..(arity - size)).map(|_| zero));
 
-            let Some(hash) = Self::hash(subtree).ok() else { Err(MerkleTreeError::HashFailed)? };
+            let Some(hash) = self.hash(subtree).ok() else { Err(MerkleTreeError::HashFailed)? };
             self.hashes.truncate(self.hashes.len() - size);
             self.hashes.push((depth + 1, hash));
         }
@@ -280,10 +464,49 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
         Ok(self)
     }
 
-    /// Poseidon hash function with circom domain tag.
-    fn hash(inputs: vec::Vec<HashBytes>) -> Result<HashBytes, Self::HashError>
+    /// Replays the `merge` loop over the depths of the pending subtrees, without hashing. Terminates under the same
+    /// conditions as `merge`, including its bound of `full_depth + 1` rounds. A tagged tree additionally derives its
+    /// zero values prior to the merge, at a hash per depth.
+    fn pending_merge_cost(
+        &self,
+        to_depth: bool
+    ) -> u32
+    {
+        if self.root != None { return 0; }
+
+        let mut depths: vec::Vec<u8> = self.hashes
+            .iter()
+            .map(|&(depth, _)| depth)
+            .collect();
+        let mut rounds = 0;
+
+        while let Some(&depth) = depths.last()
+        {
+            if depths.len() == 1 && (!to_depth || depth == self.full_depth) { break; }
+            if rounds > u32::from(self.full_depth) { break; }
+
+            let size = depths
+                .iter()
+                .rev()
+                .take_while(|d| **d == depth)
+                .count();
+
+            depths.truncate(depths.len() - size);
+            depths.push(depth + 1);
+            rounds += 1;
+        }
+
+        if self.domain_tag == [0u8; 32] { rounds }
+        else { rounds + u32::from(self.full_depth) }
+    }
+
+    /// Poseidon hash function with the circom parameters and the tree domain tag.
+    fn hash(&self, inputs: vec::Vec<HashBytes>) -> Result<HashBytes, Self::HashError>
     {
-        let mut hasher = Poseidon::<Fr>::new_circom(inputs.len())?;
+        let mut hasher = Poseidon::<Fr>::with_domain_tag_circom(
+            inputs.len(),
+            Fr::from_be_bytes_mod_order(&self.domain_tag)
+        )?;
 
         let fr_inputs: vec::Vec<Fr> = inputs
             .iter()
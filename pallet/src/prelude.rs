@@ -0,0 +1,36 @@
+//! The types required to integrate the pallet into a runtime, or to interface with it over RPC.
+//! Downstream code should prefer these re-exports over the internal module paths, which may change.
+
+pub use crate::pallet::{
+    Call,
+    Config,
+    Error,
+    Event,
+    Pallet
+};
+
+pub use crate::poll::{
+    BlockNumber,
+    Commitment,
+    CommitmentData,
+    CommitmentIndex,
+    Coordinator,
+    FinalizedPoll,
+    HashBytes,
+    MergePhase,
+    OutcomeIndex,
+    PollConfigInput,
+    PollId,
+    PollInteractionData,
+    PollInteractionMessage,
+    PollOutcome,
+    PollStatus,
+    ProofBatches,
+    ProofData,
+    PublicKey,
+    VerifyKey,
+    VerifyingKeys,
+    interaction_leaf
+};
+
+pub use crate::runtime_api::InfimumApi;
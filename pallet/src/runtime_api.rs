@@ -0,0 +1,42 @@
+use sp_std::vec;
+use crate::poll::{CommitmentIndex, HashBytes, PollId, PollStatus, ProofData, VerifyKey};
+
+sp_api::decl_runtime_apis! {
+    /// Read-only queries against the pallet state, intended for coordinators and clients.
+    pub trait InfimumApi
+    {
+        /// Returns the public inputs the pallet will supply to the verifier for the proof at `proof_index`,
+        /// where process proofs are indexed first and tally proofs follow. Returns `None` if the poll does not
+        /// exist, its state trees have not been merged, or the coordinator has no keys for its tree depths.
+        fn proof_public_inputs(
+            poll_id: PollId,
+            proof_index: CommitmentIndex,
+            curr_commitment: HashBytes,
+            new_commitment: HashBytes
+        ) -> Option<vec::Vec<HashBytes>>;
+
+        /// Returns the root the registration tree would have if it were merged now, without persisting the merge.
+        fn preview_registration_root(poll_id: PollId) -> Option<HashBytes>;
+
+        /// Returns a digest of the Poseidon round constants and MDS matrix for the state `width`, such that coordinators
+        /// may confirm their circuits use the same parameters as the pallet. Returns `None` if the width is unsupported.
+        fn poseidon_params_digest(width: u8) -> Option<HashBytes>;
+
+        /// Returns the registration tree depth supplied to the message processing circuit as a public input. This is the
+        /// depth of the non-zero leaves once the registration tree is merged, and depends on turnout. Returns `None` if the
+        /// registration tree has not been merged.
+        fn registration_state_depth(poll_id: PollId) -> Option<u8>;
+
+        /// Returns the options which may be voted for in the poll, or `None` if the poll does not exist.
+        fn vote_options(poll_id: PollId) -> Option<vec::Vec<u128>>;
+
+        /// Returns the ids of the polls with the given status amongst at most `max` polls, in ascending order starting
+        /// from `start`, alongside the id from which the subsequent page may be requested, or None if every poll has been
+        /// visited. A page may hence be empty whilst further pages remain.
+        fn polls_by_status(status: PollStatus, start: PollId, max: u32) -> (vec::Vec<PollId>, Option<PollId>);
+
+        /// Returns true iff the proof is valid for the public inputs under the supplied verification key, which need
+        /// not be registered by any coordinator. Public inputs are encoded as by `proof_public_inputs`.
+        fn verify_proof_with_key(verify_key: VerifyKey, public_inputs: vec::Vec<HashBytes>, proof: ProofData) -> bool;
+    }
+}
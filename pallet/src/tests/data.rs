@@ -5,6 +5,7 @@ use crate::poll::{
     CommitmentData,
     HashBytes,
     OutcomeIndex,
+    PollConfigInput,
     ProofData,
     PublicKey,
     PollOutcome,
@@ -62,7 +63,8 @@ pub fn get_coordinator_data() -> (PublicKey, VerifyingKeys)
     };
     let vk = VerifyingKeys {
         process: vk_process,
-        tally: vk_tally
+        tally: vk_tally,
+        circuit_version: 0
     };
 
     (pk, vk)
@@ -93,7 +95,8 @@ pub fn get_coordinator_data_malformed() -> (PublicKey, VerifyingKeys)
 
     let vk = VerifyingKeys {
         process: vk_process,
-        tally: vk_tally
+        tally: vk_tally,
+        circuit_version: 0
     };
 
     (pk, vk)
@@ -122,6 +125,29 @@ pub fn get_poll_config() -> (BlockNumber, BlockNumber, u8, u8, u8, u8, u8, vec::
     )
 }
 
+/// The parameters of `get_poll_config`, with the optional parameters of `create_poll_v2` left as default.
+pub fn get_poll_config_input() -> PollConfigInput
+{
+    let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+    PollConfigInput {
+        signup_period,
+        voting_period,
+        registration_depth,
+        interaction_depth,
+        process_subtree_depth,
+        tally_subtree_depth,
+        vote_option_tree_depth,
+        vote_options,
+        metadata: None,
+        reject_empty_messages: false,
+        min_interactions: 0,
+        max_interaction_depth: None,
+        metadata_uri: None,
+        allow_override: None
+    }
+}
+
 pub fn get_proof() -> (ProofData, HashBytes, ProofData, HashBytes)
 {
     (
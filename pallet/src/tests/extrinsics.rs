@@ -1,13 +1,19 @@
 use sp_std::vec;
 use ark_bn254::{Fr};
-use ark_ff::{PrimeField};
+use ark_ff::{BigInteger, PrimeField};
 use frame_support::{
     assert_ok, 
     assert_err, 
-    error
+    assert_err_ignore_postinfo,
+    dispatch::WithPostDispatchInfo,
+    error,
+    pallet_prelude::{Encode, Get, GetStorageVersion, Hooks, MaxEncodedLen, StorageVersion}
 };
 use crate::{
     mock::*,
+    Coordinators,
+    Polls,
+    RegisteredKeys,
     Error,
     Event
 };
@@ -19,18 +25,33 @@ use crate::tests::{
     get_participant,
     get_participants,
     get_poll_config,
+    get_poll_config_input,
     get_poll_scenario
 };
 use crate::poll::{
+    ALLOW_OVERRIDE_CIRCUIT_VERSION,
     CommitmentData,
+    CommitmentPhase,
     HashBytes,
+    MergePhase,
+    MerkleTreeError,
     PublicKey,
+    PollConfigInput,
+    PollId,
+    PollInteractionBatch,
+    PollStatus,
     ProofData,
-    provider::PollProvider
+    VerifyError,
+    VerifyingKeys,
+    provider::{PollProvider, interaction_leaf, registration_leaf},
+    zeroes::BINARY_ZEROES
 };
 use crate::hash::{
     Poseidon,
-    PoseidonHasher
+    PoseidonBytesHasher,
+    PoseidonError,
+    PoseidonHasher,
+    hashbytes_to_fr_checked
 };
 
 /// Coordinators should be able to register.
@@ -45,11 +66,35 @@ fn coordinator_registration_successful()
         // Successful registration
         assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone()));
         assert_eq!(Infimum::coordinators(0).is_some(), true);
-        System::assert_has_event(Event::CoordinatorRegistered { who: 0, public_key: pk, verify_key: vk.clone() }.into());
+        System::assert_has_event(Event::CoordinatorRegistered {
+            who: 0,
+            public_key: pk,
+            verify_key: vk.clone(),
+            process_fingerprint: vk.process.fingerprint(),
+            tally_fingerprint: vk.tally.fingerprint()
+        }.into());
+        assert_ne!(vk.process.fingerprint(), vk.tally.fingerprint());
         assert_eq!(System::events().len(), 1);
     })
 }
 
+/// The keys of registered coordinators should be retrievable.
+#[test]
+fn coordinator_keys_retrievable()
+{
+    new_test_ext().execute_with(|| {
+        let (pk, vk) = get_coordinator_data();
+
+        assert_eq!(Infimum::coordinator_public_key(&0), None);
+        assert_eq!(Infimum::coordinator_verify_keys(&0), None);
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone()));
+        assert_eq!(Infimum::coordinator_public_key(&0), Some(pk));
+        assert_eq!(Infimum::coordinator_verify_keys(&0), Some(vk));
+        assert_eq!(Infimum::coordinator_public_key(&1), None);
+    })
+}
+
 /// Coordinators should only be able to register once.
 #[test]
 fn coordinator_registration_duplicated()
@@ -73,6 +118,22 @@ fn coordinator_registration_unsigned()
     })
 }
 
+/// Coordinators must satisfy the configured coordinator origin.
+#[test]
+fn coordinator_registration_restricted()
+{
+    new_test_ext().execute_with(|| {
+        let (pk, vk) = get_coordinator_data();
+
+        RestrictCoordinators::set(true);
+        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::signed(1), pk, vk.clone()), error::BadOrigin);
+        assert_eq!(Infimum::coordinators(1).is_some(), false);
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_eq!(Infimum::coordinators(0).is_some(), true);
+    })
+}
+
 /// Coordinator verification keys must be serializable.
 #[test]
 fn coordinator_registration_malformed()
@@ -96,7 +157,13 @@ fn coordinator_key_rotation_successful()
         assert_err!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk1, vk1.clone()), Error::<Test>::CoordinatorNotRegistered);
         assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk1, vk1));
         assert_ok!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2.clone()));
-        System::assert_has_event(Event::CoordinatorKeysChanged { who: 0, public_key: pk2, verify_key: vk2 }.into());
+        System::assert_has_event(Event::CoordinatorKeysChanged {
+            who: 0,
+            public_key: pk2,
+            process_fingerprint: vk2.process.fingerprint(),
+            tally_fingerprint: vk2.tally.fingerprint(),
+            verify_key: vk2
+        }.into());
     })
 }
 
@@ -135,275 +202,2816 @@ fn coordinator_key_rotation_after_poll()
     })
 }
 
-/// Coordinator key rotation should maintain integrity of keys.
+/// Coordinators should be able to correct their verification keys before creating a poll, but not during one.
 #[test]
-fn coordinator_key_rotation_malformed() 
+fn coordinator_verify_key_rotation()
 {
     new_test_ext().execute_with(|| {
-        let (pk1, vk1) = get_coordinator_data();
-        let (pk2, vk2) = get_coordinator_data_malformed();
+        System::set_block_number(1);
 
-        assert_err!(Infimum::rotate_keys(RuntimeOrigin::none(), pk1, vk1.clone()), error::BadOrigin);
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk1, vk1));
-        assert_err!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2), Error::<Test>::MalformedKeys);
+        let (pk, vk) = get_coordinator_data();
+        let (_, malformed_vk) = get_coordinator_data_malformed();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        // Register with the process and tally keys mistakenly transposed.
+        let mut mistaken_vk = vk.clone();
+        mistaken_vk.process = vk.tally.clone();
+        mistaken_vk.tally = vk.process.clone();
+
+        assert_err!(Infimum::rotate_verify_key(RuntimeOrigin::none(), vk.clone()), error::BadOrigin);
+        assert_err!(Infimum::rotate_verify_key(RuntimeOrigin::signed(0), vk.clone()), Error::<Test>::CoordinatorNotRegistered);
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, mistaken_vk));
+        assert_err!(Infimum::rotate_verify_key(RuntimeOrigin::signed(0), malformed_vk), Error::<Test>::MalformedKeys);
+
+        // The coordinator has not yet created a poll, so the keys may be corrected freely.
+        assert_eq!(Infimum::coordinators(0).unwrap().last_poll, None);
+        assert_ok!(Infimum::rotate_verify_key(RuntimeOrigin::signed(0), vk.clone()));
+        assert_eq!(Infimum::coordinator_verify_keys(&0), Some(vk.clone()));
+        assert_eq!(Infimum::coordinator_public_key(&0), Some(pk));
+        System::assert_last_event(Event::CoordinatorKeysChanged {
+            who: 0,
+            public_key: pk,
+            process_fingerprint: vk.process.fingerprint(),
+            tally_fingerprint: vk.tally.fingerprint(),
+            verify_key: vk.clone()
+        }.into());
+
+        // The keys may not be replaced once they are in use by an active poll.
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_err!(Infimum::rotate_verify_key(RuntimeOrigin::signed(0), vk), Error::<Test>::PollCurrentlyActive);
     })
 }
 
-/// Coordinators should be able to create polls.
+/// Coordinators with depth specific keys should only be able to commit outcomes for polls of matching depths.
 #[test]
-fn poll_creation_successful() 
+fn coordinator_depth_keys()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let (pk, vk) = get_coordinator_data();
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (_pk, malformed_vk) = get_coordinator_data_malformed();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_err!(Infimum::register_depth_keys(RuntimeOrigin::signed(0), 4, interaction_depth, alice_vk.clone()), Error::<Test>::CoordinatorNotRegistered);
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk.clone()));
+        assert_err!(Infimum::register_depth_keys(RuntimeOrigin::signed(0), 4, interaction_depth, malformed_vk), Error::<Test>::MalformedKeys);
+        assert_ok!(Infimum::register_depth_keys(RuntimeOrigin::signed(0), 4, interaction_depth, alice_vk.clone()));
+        System::assert_has_event(Event::CoordinatorDepthKeysChanged {
+            who: 0,
+            registration_depth: 4,
+            interaction_depth,
+            process_fingerprint: alice_vk.process.fingerprint(),
+            tally_fingerprint: alice_vk.tally.fingerprint(),
+            verify_key: alice_vk.clone()
+        }.into());
 
-        assert_eq!(Infimum::coordinators(0).unwrap().last_poll, Some(0));
-        assert_eq!(Infimum::poll_ids(0).len(), 1);        
+        let coordinator = Infimum::coordinators(0).unwrap();
+        assert_eq!(coordinator.verify_keys_for(4, interaction_depth), Some(alice_vk.clone()));
+        assert_eq!(coordinator.verify_keys_for(registration_depth, interaction_depth), None);
 
-        System::assert_has_event(Event::PollCreated {
-            coordinator: 0,
-            poll_id: 0,
-            starts_at: 1 + signup_period,
-            ends_at: 2 + signup_period + voting_period
-        }.into());
-    })
-}
+        // Polls may not be created for depths without registered keys.
+        assert_err!(
+            Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()),
+            Error::<Test>::NoVerifyKeyForDepth
+        );
+        assert_eq!(Infimum::total_polls(), 0);
 
-/// Polls can only be created by registered coordinators.
-#[test]
-fn poll_creation_by_non_coordinator() 
-{
-    new_test_ext().execute_with(|| {
-        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        assert_ok!(Infimum::register_depth_keys(RuntimeOrigin::signed(0), registration_depth, interaction_depth, alice_vk.clone()));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
 
-        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options), Error::<Test>::CoordinatorNotRegistered);
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None));
     })
 }
 
-/// Polls should be able to be nullified.
+/// Depth specific keys should be bounded, required for every depth a poll may grow to, and selected by its merged depths.
 #[test]
-fn poll_nullify_error() 
+fn coordinator_depth_keys_configured_depth()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
         let (pk, vk) = get_coordinator_data();
-        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let (signup_period, voting_period, registration_depth, ..) = get_poll_config();
+        let max_depth_keys = <Test as crate::Config>::MaxDepthKeys::get() as u8;
 
-        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::CoordinatorNotRegistered);
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone()));
+        for depth in 0..max_depth_keys - 2
+        {
+            assert_ok!(Infimum::register_depth_keys(RuntimeOrigin::signed(0), depth, depth, vk.clone()));
+        }
+        assert_ok!(Infimum::register_depth_keys(RuntimeOrigin::signed(0), registration_depth, 1, vk.clone()));
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::PollDoesNotExist);
+        // Keys are required for every depth the interaction tree may grow to.
+        assert_err!(
+            Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { interaction_depth: 1, max_interaction_depth: Some(2), ..get_poll_config_input() }),
+            Error::<Test>::NoVerifyKeyForDepth
+        );
 
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_ok!(Infimum::register_depth_keys(RuntimeOrigin::signed(0), registration_depth, 2, vk.clone()));
+        assert_err!(Infimum::register_depth_keys(RuntimeOrigin::signed(0), registration_depth, 3, vk.clone()), Error::<Test>::TooManyDepthKeys);
+
+        // Replacing the keys of registered depths is permitted at the bound.
+        assert_ok!(Infimum::register_depth_keys(RuntimeOrigin::signed(0), registration_depth, 1, vk));
+        assert_eq!(Infimum::coordinators(0).unwrap().depth_keys.len(), max_depth_keys as usize);
+
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { interaction_depth: 1, max_interaction_depth: Some(2), ..get_poll_config_input() }));
+        let config = Infimum::polls(0).unwrap().config;
+        assert_eq!((config.registration_depth, config.interaction_depth), (registration_depth, 1));
 
         let (pk, shared_pk, message) = get_participant();
         assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
-        
-        run_to_block(1 + signup_period);
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
-        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::PollCurrentlyActive);
+
+        run_to_block(2 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        for _ in 0..6
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        }
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // The interaction tree outgrew its configured depth, hence the keys are selected by its merged depth.
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.full_depth, 2);
+        assert!(Infimum::proof_public_inputs(0, 0, [0u8; 32], [0u8; 32]).is_some());
     })
 }
 
-/// Coordinators can only create the allowed maximum number of polls.
+/// Coordinator key rotation should maintain integrity of keys.
 #[test]
-fn poll_creation_beyond_limit() 
+fn coordinator_key_rotation_malformed() 
+{
+    new_test_ext().execute_with(|| {
+        let (pk1, vk1) = get_coordinator_data();
+        let (pk2, vk2) = get_coordinator_data_malformed();
+
+        assert_err!(Infimum::rotate_keys(RuntimeOrigin::none(), pk1, vk1.clone()), error::BadOrigin);
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk1, vk1));
+        assert_err!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2), Error::<Test>::MalformedKeys);
+    })
+}
+
+/// Coordinators should be able to create polls.
+#[test]
+fn poll_creation_successful() 
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
         let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
-        let duration = signup_period + voting_period;
 
         assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
         assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
 
-        run_to_block(2 + duration);
-        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+        assert_eq!(Infimum::coordinators(0).unwrap().last_poll, Some(0));
+        assert_eq!(Infimum::poll_ids(0).len(), 1);        
+        assert_eq!(Infimum::poll_coordinator(0), Some(0));
+        assert_eq!(Infimum::poll_coordinator(1), None);
+        assert_eq!(Infimum::vote_options(0), Some(vote_options.clone()));
+        assert_eq!(Infimum::vote_options(1), None);
+
+        // The poll is created at block 1, and is over from the block following the final block of the voting period.
+        let voting_period_end = Infimum::voting_period_end(0).unwrap();
+        assert_eq!(voting_period_end, 1 + signup_period + voting_period);
+        System::assert_has_event(Event::PollCreated {
+            coordinator: 0,
+            poll_id: 0,
+            starts_at: 1 + signup_period,
+            ends_at: voting_period_end + 1,
+            vote_options: vote_options.try_into().unwrap()
+        }.into());
 
-        run_to_block(2 + 2 * duration);
-        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
-        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options), Error::<Test>::CoordinatorPollLimitReached);
+        run_to_block(voting_period_end);
+        assert!(!Infimum::polls(0).unwrap().is_over());
+        run_to_block(voting_period_end + 1);
+        assert!(Infimum::polls(0).unwrap().is_over());
     })
 }
 
-/// A coordinator can only manage a single poll at a time.
+/// Poll creation events should remain bounded by the maximum number of vote options.
 #[test]
-fn poll_creation_during_extant() 
+fn poll_creation_max_vote_options()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
         let (pk, vk) = get_coordinator_data();
-        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, _) = get_poll_config();
+        let max_vote_options = <Test as crate::Config>::MaxVoteOptions::get();
+        let vote_options: vec::Vec<u128> = (0..max_vote_options as u128).collect();
+        let excess_vote_options: vec::Vec<u128> = (0..=max_vote_options as u128).collect();
 
         assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, excess_vote_options), Error::<Test>::TooManyVoteOptions);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vec::Vec::from([0])), Error::<Test>::PollConfigInvalid);
         assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
-        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options), Error::<Test>::PollCurrentlyActive);
+
+        let bounded_vote_options: crate::VoteOptions<Test> = vote_options.try_into().unwrap();
+        assert!(bounded_vote_options.encoded_size() <= crate::VoteOptions::<Test>::max_encoded_len());
+
+        System::assert_has_event(Event::PollCreated {
+            coordinator: 0,
+            poll_id: 0,
+            starts_at: 1 + signup_period,
+            ends_at: 2 + signup_period + voting_period,
+            vote_options: bounded_vote_options
+        }.into());
     })
 }
 
-/// Users should be able to register as participants.
+/// Poll creation may be disabled by root without affecting extant polls.
 #[test]
-fn register_as_participant()
+fn poll_creation_toggle()
 {
-    new_test_ext().execute_with(|| { 
+    new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
         let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone()));
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(1), pk, vk));
         assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
-        
-        let participant = get_participant();
+        assert_eq!(Infimum::poll_creation_enabled(), true);
 
-        assert_err!(Infimum::register_as_participant(RuntimeOrigin::none(), 0, participant.0), error::BadOrigin);
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0));
-        
-        assert_eq!(Infimum::polls(0).is_some(), true);
-        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count, 1);
+        assert_err!(Infimum::set_poll_creation_enabled(RuntimeOrigin::signed(0), false), error::BadOrigin);
+        assert_ok!(Infimum::set_poll_creation_enabled(RuntimeOrigin::root(), false));
+        assert_eq!(Infimum::poll_creation_enabled(), false);
+        System::assert_last_event(Event::PollCreationToggled { enabled: false }.into());
 
-        System::assert_has_event(Event::ParticipantRegistered { poll_id: 0, count: 1, public_key: participant.0, block: 1 }.into());
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(1), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()), Error::<Test>::PollCreationDisabled);
+
+        // Extant polls continue to function.
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+        run_to_block(2 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        assert_ok!(Infimum::set_poll_creation_enabled(RuntimeOrigin::root(), true));
+        System::assert_last_event(Event::PollCreationToggled { enabled: true }.into());
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
     })
 }
 
-/// Users can only register during the registration period.
+/// Poll metadata should be bounded, and committed to by its keccak hash.
 #[test]
-fn register_as_participant_outside_period()
+fn poll_creation_with_metadata()
 {
-    new_test_ext().execute_with(|| { 
+    new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
         let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let metadata = b"Which option should be adopted?".to_vec();
+        let max_len = <Test as crate::Config>::MaxMetadataLen::get() as usize;
+        let bounded = vec![0u8; max_len];
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
-        
-        let participant = get_participant();
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone()));
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(1), pk, vk.clone()));
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(2), pk, vk));
+        assert_err!(
+            Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { metadata: Some(vec![0u8; max_len + 1]), ..get_poll_config_input() }),
+            Error::<Test>::MetadataTooLong
+        );
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { metadata: Some(metadata.clone()), ..get_poll_config_input() }));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(2), PollConfigInput { metadata: Some(bounded.clone()), ..get_poll_config_input() }));
 
-        run_to_block(1 + signup_period);
-        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0), Error::<Test>::PollRegistrationHasEnded);
+        assert_eq!(Infimum::polls(0).unwrap().config.metadata_hash, Some(sp_io::hashing::keccak_256(&metadata)));
+        assert_eq!(Infimum::polls(1).unwrap().config.metadata_hash, None);
+        assert_eq!(Infimum::polls(2).unwrap().config.metadata_hash, Some(sp_io::hashing::keccak_256(&bounded)));
     })
 }
 
-/// Only the allowable number of participants should be allowed to register.
+/// Polls created from named parameters should match polls created from the equivalent positional parameters, with the
+/// optional parameters left as default.
 #[test]
-fn participant_limit_reached()
+fn poll_creation_from_config_input()
 {
-    new_test_ext().execute_with(|| { 
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
         let (pk, vk) = get_coordinator_data();
-        let (signup_period, voting_period, _registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let config = get_poll_config_input();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, 2, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
-        
-        let participant = get_participant();
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0));
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 0, participant.0));
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(3), 0, participant.0));
-        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(4), 0, participant.0), Error::<Test>::ParticipantRegistrationLimitReached);
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone()));
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(1), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(1), config.clone()));
+
+        let positional = Infimum::polls(0).unwrap();
+        let named = Infimum::polls(1).unwrap();
+        assert_eq!(named.coordinator, 1);
+        assert_eq!(named.config, positional.config);
+        assert_eq!(named.state, positional.state);
+
+        // The named parameters are subject to the same validation.
+        assert_err!(
+            Infimum::create_poll_v2(RuntimeOrigin::signed(1), PollConfigInput { max_interaction_depth: Some(interaction_depth - 1), ..config }),
+            Error::<Test>::PollConfigInvalid
+        );
     })
 }
 
-/// Users can only register in existing polls.
+/// Poll metadata uris should be bounded, and only updated by the coordinator before the voting period ends.
 #[test]
-fn participant_registration_no_poll()
+fn poll_metadata_uri()
 {
-    new_test_ext().execute_with(|| { 
-        let participant = get_participant();
-        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0), Error::<Test>::PollDoesNotExist);
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, ..) = get_poll_config();
+        let max_len = <Test as crate::Config>::MaxMetadataLen::get() as usize;
+        let uri = b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_err!(
+            Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { metadata_uri: Some(vec![0u8; max_len + 1]), ..get_poll_config_input() }),
+            Error::<Test>::MetadataTooLong
+        );
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { metadata_uri: Some(uri.clone()), ..get_poll_config_input() }));
+        assert_eq!(Infimum::polls(0).unwrap().config.metadata_uri.unwrap().to_vec(), uri);
+
+        // Only the coordinator of an extant poll may update its metadata uri.
+        assert_err!(Infimum::set_poll_metadata(RuntimeOrigin::signed(1), 0, None), Error::<Test>::NotPollCoordinator);
+        assert_err!(Infimum::set_poll_metadata(RuntimeOrigin::signed(0), 1, None), Error::<Test>::PollDoesNotExist);
+        assert_err!(
+            Infimum::set_poll_metadata(RuntimeOrigin::signed(0), 0, Some(vec![0u8; max_len + 1])),
+            Error::<Test>::MetadataTooLong
+        );
+
+        // A metadata uri of exactly the maximum length is accepted.
+        assert_ok!(Infimum::set_poll_metadata(RuntimeOrigin::signed(0), 0, Some(vec![0u8; max_len])));
+        assert_eq!(Infimum::polls(0).unwrap().config.metadata_uri.unwrap().len(), max_len);
+
+        // The metadata uri may be updated during the voting period.
+        run_to_block(2 + signup_period);
+        let updated = b"bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku".to_vec();
+        assert_ok!(Infimum::set_poll_metadata(RuntimeOrigin::signed(0), 0, Some(updated.clone())));
+        System::assert_last_event(Event::PollMetadataUpdated { poll_id: 0, metadata_uri: Some(updated.clone()) }.into());
+        assert_eq!(Infimum::polls(0).unwrap().config.metadata_uri.unwrap().to_vec(), updated);
+
+        // Once the voting period has ended the metadata uri is fixed.
+        run_to_block(1 + signup_period + voting_period + 1);
+        assert_err!(Infimum::set_poll_metadata(RuntimeOrigin::signed(0), 0, None), Error::<Test>::PollVotingHasEnded);
     })
 }
 
-/// Participants should be able to interact with polls they are registered in.
+/// Coordinator metadata should be bounded, and only set by a registered coordinator.
 #[test]
-fn participant_interaction()
+fn coordinator_metadata()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
         let (pk, vk) = get_coordinator_data();
-        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let max_len = <Test as crate::Config>::MaxMetadataLen::get() as usize;
+        let metadata = b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec();
 
+        assert_err!(Infimum::set_coordinator_metadata(RuntimeOrigin::signed(0), Some(metadata.clone())), Error::<Test>::CoordinatorNotRegistered);
         assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_eq!(Infimum::coordinators(0).unwrap().metadata, None);
 
-        let (pk, shared_pk, message) = get_participant();
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
-        
-        run_to_block(1 + signup_period);
-        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::none(), 0, shared_pk, message), error::BadOrigin);
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        assert_err!(Infimum::set_coordinator_metadata(RuntimeOrigin::signed(0), Some(vec![0u8; max_len + 1])), Error::<Test>::MetadataTooLong);
+        assert_ok!(Infimum::set_coordinator_metadata(RuntimeOrigin::signed(0), Some(vec![0u8; max_len])));
+        assert_eq!(Infimum::coordinators(0).unwrap().metadata.unwrap().len(), max_len);
 
-        assert_eq!(Infimum::polls(0).is_some(), true);
-        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 1);
+        assert_ok!(Infimum::set_coordinator_metadata(RuntimeOrigin::signed(0), Some(metadata.clone())));
+        System::assert_last_event(Event::CoordinatorMetadataUpdated { who: 0, metadata: Some(metadata.clone()) }.into());
+        assert_eq!(Infimum::coordinators(0).unwrap().metadata.unwrap().to_vec(), metadata);
 
-        System::assert_has_event(Event::PollInteraction { poll_id: 0, count: 1, public_key: shared_pk, data: message }.into());
+        assert_ok!(Infimum::set_coordinator_metadata(RuntimeOrigin::signed(0), None));
+        assert_eq!(Infimum::coordinators(0).unwrap().metadata, None);
     })
 }
 
-/// Participants should only be able to interact during the voting period.
+/// Polls whose deadlines cannot be represented as a block number should be rejected.
 #[test]
-fn participant_interaction_outside_period()
+fn poll_creation_near_max_block_number()
 {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
-
         let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
         assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+
+        System::set_block_number(u64::MAX - signup_period - voting_period);
+        assert_err!(
+            Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()),
+            Error::<Test>::BlockNumberOutOfRange
+        );
+
+        // The final block of the voting period immediately precedes the maximum block number.
+        let created_at = u64::MAX - signup_period - voting_period - 1;
+        System::set_block_number(created_at);
         assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
 
         let (pk, shared_pk, message) = get_participant();
         assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
 
-        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::PollRegistrationInProgress);
-        run_to_block(2 + signup_period + voting_period);
+        System::set_block_number(u64::MAX - 1);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
 
+        System::set_block_number(u64::MAX);
         assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::PollVotingHasEnded);
     })
 }
 
-/// The maximal number of allowable interactions should be enforced.
+/// Coordinators should not be able to create successive polls within the runtime cooldown.
 #[test]
-fn participant_interaction_limit()
+fn poll_creation_cooldown()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
         let (pk, vk) = get_coordinator_data();
-        let (signup_period, voting_period, registration_depth, _interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        PollCreationCooldown::set(40);
 
         assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, 1, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+        assert_eq!(Infimum::coordinators(0).unwrap().last_poll_created_at, Some(1));
+
+        // A poll without registrations may be nullified once its registration period ends.
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+
+        // The nullified poll has ended, but the cooldown has not elapsed.
+        run_to_block(2 + signup_period + voting_period);
+        assert_err!(
+            Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()),
+            Error::<Test>::PollCreationCooldown
+        );
+
+        run_to_block(40);
+        assert_err!(
+            Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()),
+            Error::<Test>::PollCreationCooldown
+        );
+
+        run_to_block(41);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_eq!(Infimum::coordinators(0).unwrap().last_poll_created_at, Some(41));
+    })
+}
+
+/// Polls with registration or voting periods shorter than the runtime minimums should be rejected.
+#[test]
+fn poll_creation_min_periods()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        MinSignupPeriod::set(signup_period);
+        MinVotingPeriod::set(voting_period);
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone()));
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(1), pk, vk));
+        assert_err!(
+            Infimum::create_poll(RuntimeOrigin::signed(0), signup_period - 1, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()),
+            Error::<Test>::PollConfigInvalid
+        );
+        assert_err!(
+            Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period - 1, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()),
+            Error::<Test>::PollConfigInvalid
+        );
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+
+        // Without a floor, polls may have periods of any length.
+        MinSignupPeriod::set(0);
+        MinVotingPeriod::set(0);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), 1, 1, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+    })
+}
+
+/// Polls whose state trees are deeper than the zero tables should be rejected rather than panic when merged.
+#[test]
+fn poll_creation_exceeds_zero_table()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, _registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let excess_depth = BINARY_ZEROES.len() as u8;
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_err!(
+            Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, excess_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()),
+            Error::<Test>::PollConfigInvalid
+        );
+        assert_err!(
+            Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { max_interaction_depth: Some(excess_depth), ..get_poll_config_input() }),
+            Error::<Test>::PollConfigInvalid
+        );
+
+        // Degenerate state trees of depth zero are likewise rejected.
+        assert_err!(
+            Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, 0, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options),
+            Error::<Test>::PollConfigInvalid
+        );
+        assert_eq!(Infimum::total_polls(), 0);
+    })
+}
+
+/// Poll ids should be assigned monotonically across coordinators.
+#[test]
+fn poll_creation_monotonic_ids()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_eq!(Infimum::total_polls(), 0);
+
+        for coordinator in 0..3
+        {
+            assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(coordinator), pk, vk.clone()));
+            assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(coordinator), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+            assert_eq!(Infimum::coordinators(coordinator).unwrap().last_poll, Some(coordinator as u32));
+            assert_eq!(Infimum::total_polls(), coordinator as u32 + 1);
+        }
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        assert_eq!(Infimum::coordinators(0).unwrap().last_poll, Some(3));
+        assert_eq!(Infimum::poll_ids(0), vec::Vec::from([0, 3]));
+        assert_eq!(Infimum::total_polls(), 4);
+    })
+}
+
+/// A coordinator should be able to restore their most recent poll from the polls they manage.
+#[test]
+fn coordinator_resync_last_poll()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_err!(Infimum::resync_last_poll(RuntimeOrigin::signed(0)), Error::<Test>::CoordinatorNotRegistered);
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert!(Infimum::is_last_poll_synced(&0));
+
+        // A consistent coordinator is unaffected.
+        System::reset_events();
+        assert_ok!(Infimum::resync_last_poll(RuntimeOrigin::signed(0)));
+        assert_eq!(System::events().len(), 0);
+
+        Coordinators::<Test>::mutate(0, |coordinator| {
+            coordinator.as_mut().unwrap().last_poll = None;
+        });
+        assert!(!Infimum::is_last_poll_synced(&0));
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollDoesNotExist);
+
+        assert_ok!(Infimum::resync_last_poll(RuntimeOrigin::signed(0)));
+        assert_eq!(Infimum::coordinators(0).unwrap().last_poll, Some(0));
+        assert!(Infimum::is_last_poll_synced(&0));
+        System::assert_last_event(Event::CoordinatorLastPollResynced { who: 0, last_poll: Some(0) }.into());
+    })
+}
+
+/// Polls whose tree depths exceed the capacity of the state trees should be rejected.
+#[test]
+fn poll_creation_over_capacity()
+{
+    new_test_ext().execute_with(|| {
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+
+        // Interaction tree capacity exceeds `MaxPollInteractions`.
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, 5, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()), Error::<Test>::PollConfigInvalid);
+
+        // Tree capacities which are not representable.
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, 14, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()), Error::<Test>::PollConfigInvalid);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, 32, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()), Error::<Test>::PollConfigInvalid);
+
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_eq!(Infimum::polls(0).unwrap().config.max_interactions, 25);
+    })
+}
+
+/// The interaction cap of a poll should never exceed the runtime interaction cap.
+#[test]
+fn poll_creation_max_interactions_reconciled()
+{
+    new_test_ext().execute_with(|| {
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, _interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let max_interactions: u32 = <Test as crate::Config>::MaxPollInteractions::get();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+
+        // 5^5 interactions exceeds the runtime cap.
+        assert!(5u32.pow(5) > max_interactions);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, 5, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()), Error::<Test>::PollConfigInvalid);
+
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, 4, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_eq!(Infimum::polls(0).unwrap().config.max_interactions, 625);
+        assert!(Infimum::polls(0).unwrap().config.max_interactions <= max_interactions);
+    })
+}
+
+/// Polls whose proof batches span more than the state trees should be rejected.
+#[test]
+fn poll_creation_oversized_subtrees()
+{
+    new_test_ext().execute_with(|| {
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, _process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, interaction_depth + 1, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()), Error::<Test>::PollConfigInvalid);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, interaction_depth, registration_depth + 1, vote_option_tree_depth, vote_options.clone()), Error::<Test>::PollConfigInvalid);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, interaction_depth, registration_depth, vote_option_tree_depth, vote_options));
+    })
+}
+
+/// Polls can only be created by registered coordinators.
+#[test]
+fn poll_creation_by_non_coordinator() 
+{
+    new_test_ext().execute_with(|| {
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options), Error::<Test>::CoordinatorNotRegistered);
+    })
+}
+
+/// Polls should be able to be nullified.
+#[test]
+fn poll_nullify_error() 
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::CoordinatorNotRegistered);
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::PollDoesNotExist);
+
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::PollCurrentlyActive);
+    })
+}
+
+/// Nullified polls should reject every subsequent mutation.
+#[test]
+fn poll_nullified_inactive()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        System::assert_has_event(Event::PollNullified { poll_id: 0 }.into());
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk), Error::<Test>::PollInactive);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::PollInactive);
+        assert_err!(Infimum::submit_vote(RuntimeOrigin::signed(1), 0, shared_pk, message, None), Error::<Test>::PollInactive);
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollInactive);
+
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+        assert_err_ignore_postinfo!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::PollInactive);
+
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 0);
+    })
+}
+
+/// Polls should only be nullified once.
+#[test]
+fn poll_nullified_once()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::PollAlreadyNullified);
+
+        let nullified = System::events()
+            .into_iter()
+            .filter(|record| matches!(record.event, RuntimeEvent::Infimum(Event::PollNullified { poll_id: 0 })))
+            .count();
+        assert_eq!(nullified, 1);
+    })
+}
+
+/// Polls which record fewer interactions than their minimum should not be merged, and may be nullified instead.
+#[test]
+fn poll_insufficient_interactions()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, ..) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_err!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { min_interactions: 26, ..get_poll_config_input() }), Error::<Test>::PollConfigInvalid);
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { min_interactions: 2, ..get_poll_config_input() }));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(2 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::InsufficientInteractions);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert!(Infimum::polls(0).unwrap().is_nullified());
+    })
+}
+
+/// The working state of the state trees should be discarded once a poll is nullified.
+#[test]
+fn poll_nullified_pruned()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, ..) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { min_interactions: 3, ..get_poll_config_input() }));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(2 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+
+        run_to_block(2 + signup_period + voting_period);
+        let poll = Infimum::polls(0).unwrap();
+        assert_eq!(poll.state.interactions.hashes.len(), 2);
+        assert_eq!(poll.state.interactions.root, None);
+
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+
+        let nullified = Infimum::polls(0).unwrap();
+        assert!(nullified.is_nullified());
+        assert!(nullified.state.registrations.hashes.is_empty());
+        assert!(nullified.state.interactions.hashes.is_empty());
+        assert_eq!(nullified.state.registrations.root, poll.state.registrations.root);
+        assert_eq!(nullified.state.registrations.count, 1);
+        assert_eq!(nullified.state.interactions.count, 2);
+    })
+}
+
+/// The working state of finalized polls should be prunable by their coordinator.
+#[test]
+fn poll_prune()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(2 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_err!(Infimum::prune_poll(RuntimeOrigin::signed(0), 1), Error::<Test>::PollDoesNotExist);
+        assert_err!(Infimum::prune_poll(RuntimeOrigin::signed(1), 0), Error::<Test>::NotPollCoordinator);
+        assert_err!(Infimum::prune_poll(RuntimeOrigin::signed(0), 0), Error::<Test>::PollCurrentlyActive);
+
+        // Simulate a committed outcome.
+        Polls::<Test>::mutate(0, |poll| {
+            poll.as_mut().unwrap().state.outcome = Some(1);
+        });
+        let poll = Infimum::polls(0).unwrap();
+        assert!(poll.state.interactions.hashes.len() > 0);
+
+        assert_ok!(Infimum::prune_poll(RuntimeOrigin::signed(0), 0));
+        System::assert_last_event(Event::PollPruned { poll_id: 0 }.into());
+
+        let pruned = Infimum::polls(0).unwrap();
+        assert!(pruned.state.registrations.hashes.is_empty());
+        assert!(pruned.state.interactions.hashes.is_empty());
+        assert_eq!(pruned.state.registrations.root, poll.state.registrations.root);
+        assert_eq!(pruned.state.outcome, Some(1));
+        assert_eq!(pruned.config, poll.config);
+    })
+}
+
+/// Polls which ended without any interactions should only be finalizable by nullification.
+#[test]
+fn poll_no_interactions_to_tally()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(2 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+
+        // The voting period is not yet over.
+        assert_err_ignore_postinfo!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches.clone(), None), Error::<Test>::PollStateNotMerged);
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::NoInteractionsToTally);
+        assert_err_ignore_postinfo!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches.clone(), None), Error::<Test>::NoInteractionsToTally);
+
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert!(Infimum::polls(0).unwrap().is_fulfilled());
+        assert_err_ignore_postinfo!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::PollInactive);
+    })
+}
+
+/// Coordinators can only create the allowed maximum number of polls.
+#[test]
+fn poll_creation_beyond_limit() 
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let duration = signup_period + voting_period;
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+
+        run_to_block(2 + duration);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+
+        run_to_block(2 + 2 * duration);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options), Error::<Test>::CoordinatorPollLimitReached);
+    })
+}
+
+/// A coordinator can only manage a single poll at a time.
+#[test]
+fn poll_creation_during_extant() 
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options), Error::<Test>::PollCurrentlyActive);
+    })
+}
+
+/// Users should be able to register as participants.
+#[test]
+fn register_as_participant()
+{
+    new_test_ext().execute_with(|| { 
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+        
+        let participant = get_participant();
+
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::none(), 0, participant.0), error::BadOrigin);
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0));
+        
+        assert_eq!(Infimum::polls(0).is_some(), true);
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count, 1);
+        assert_eq!(Infimum::has_registered(0, &1), true);
+        assert_eq!(Infimum::has_registered(0, &2), false);
+        assert_eq!(Infimum::has_registered(1, &1), false);
+
+        System::assert_has_event(Event::ParticipantRegistered { poll_id: 0, count: 1, public_key: participant.0, block: 1 }.into());
+    })
+}
+
+/// Users can only register during the registration period.
+#[test]
+fn register_as_participant_outside_period()
+{
+    new_test_ext().execute_with(|| { 
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+        
+        let participant = get_participant();
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0), Error::<Test>::PollRegistrationHasEnded);
+    })
+}
+
+/// Only the allowable number of participants should be allowed to register.
+#[test]
+fn participant_limit_reached()
+{
+    new_test_ext().execute_with(|| { 
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, _registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, 2, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+        
+        let participant = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0));
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 0, participant.0));
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(3), 0, participant.0));
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(4), 0, participant.0), Error::<Test>::ParticipantRegistrationLimitReached);
+    })
+}
+
+/// Users can only register in existing polls.
+#[test]
+fn participant_registration_no_poll()
+{
+    new_test_ext().execute_with(|| { 
+        let participant = get_participant();
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0), Error::<Test>::PollDoesNotExist);
+    })
+}
+
+/// Participants should be able to interact with polls they are registered in.
+#[test]
+fn participant_interaction()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::none(), 0, shared_pk, message), error::BadOrigin);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+
+        assert_eq!(Infimum::polls(0).is_some(), true);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 1);
+        assert_eq!(Infimum::interaction_count(0, shared_pk), 1);
+
+        System::assert_has_event(Event::PollInteraction { poll_id: 0, count: 1, depth: 0, public_key: shared_pk, data: message }.into());
+    })
+}
+
+/// Public keys whose coordinates are both zero should be rejected.
+#[test]
+fn zero_public_key()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let zero_pk = PublicKey { x: [0u8; 32], y: [0u8; 32] };
+        assert!(zero_pk.is_zero());
+        assert!(!pk.is_zero());
+
+        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), zero_pk, vk.clone()), Error::<Test>::ZeroPublicKey);
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone()));
+        assert_err!(Infimum::rotate_keys(RuntimeOrigin::signed(0), zero_pk, vk), Error::<Test>::ZeroPublicKey);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, _shared_pk, message) = get_participant();
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, zero_pk), Error::<Test>::ZeroPublicKey);
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, zero_pk, message), Error::<Test>::ZeroPublicKey);
+        assert_err!(Infimum::submit_vote(RuntimeOrigin::signed(1), 0, zero_pk, message, None), Error::<Test>::ZeroPublicKey);
+
+        let batch: PollInteractionBatch<Test> = vec::Vec::from([(zero_pk, message)]).try_into().unwrap();
+        assert_err_ignore_postinfo!(Infimum::interact_batch(RuntimeOrigin::signed(1), 0, batch), Error::<Test>::ZeroPublicKey);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 0);
+    })
+}
+
+/// Public keys which are not points on the Baby Jubjub curve should be rejected.
+#[test]
+fn participant_invalid_public_key()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        let mut off_curve_pk = pk;
+        off_curve_pk.x[0] = 1;
+        let non_canonical_pk = PublicKey { x: [255u8; 32], y: [255u8; 32] };
+
+        assert!(pk.is_on_curve());
+        assert!(shared_pk.is_on_curve());
+        assert!(!off_curve_pk.is_on_curve());
+        assert!(!non_canonical_pk.is_on_curve());
+
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, off_curve_pk), Error::<Test>::InvalidPublicKey);
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, non_canonical_pk), Error::<Test>::InvalidPublicKey);
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, off_curve_pk, message), Error::<Test>::InvalidPublicKey);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 1);
+    })
+}
+
+/// Interaction leaves should be computed for messages of arbitrary width.
+#[test]
+fn interaction_message_width()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let to_fr = |fields: &[[u8; 32]]| -> vec::Vec<Fr> { fields.iter().map(|bytes| Fr::from_be_bytes_mod_order(bytes)).collect() };
+        let to_bytes = |element: Fr| -> HashBytes {
+            let bytes = element.into_bigint().to_bytes_be();
+            let mut result = [0u8; 32];
+            result[..bytes.len()].copy_from_slice(&bytes);
+            result
+        };
+        let mut hash4 = Poseidon::<Fr>::new_circom(4).unwrap();
+        let mut hash5 = Poseidon::<Fr>::new_circom(5).unwrap();
+
+        // The default message width retains the original leaf schema.
+        let (_, shared_pk, message) = get_participant();
+        let left = hash5.hash(&to_fr(&message[0..5])).unwrap();
+        let right = hash5.hash(&to_fr(&message[5..10])).unwrap();
+        let expected = hash4.hash(&[ left, right, to_fr(&[shared_pk.x])[0], to_fr(&[shared_pk.y])[0] ]).unwrap();
+        assert_eq!(interaction_leaf(&shared_pk, &message).unwrap(), to_bytes(expected));
+
+        // Wider messages fold the trailing partial chunk with a narrower hash.
+        let mut wide_message = [[0u8; 32]; 14];
+        for (i, field) in wide_message.iter_mut().enumerate() { field[31] = i as u8 + 1; }
+        let first = hash5.hash(&to_fr(&wide_message[0..5])).unwrap();
+        let second = hash5.hash(&to_fr(&wide_message[5..10])).unwrap();
+        let third = hash4.hash(&to_fr(&wide_message[10..14])).unwrap();
+        let expected = to_bytes(hash5.hash(&[ first, second, third, to_fr(&[shared_pk.x])[0], to_fr(&[shared_pk.y])[0] ]).unwrap());
+
+        run_to_block(1 + signup_period);
+        let (count, filled, poll) = Infimum::polls(0).unwrap().consume_interaction(shared_pk, wide_message).unwrap();
+        assert_eq!(count, 1);
+        assert!(!filled);
+        assert_eq!(poll.state.interactions.hashes, vec::Vec::from([(0, expected)]));
+    })
+}
+
+/// Participants should be able to submit votes with a vote option hint, which is bound checked.
+#[test]
+fn participant_submit_vote()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let num_options = vote_options.len() as u32;
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::submit_vote(RuntimeOrigin::signed(1), 1, shared_pk, message, None), Error::<Test>::PollDoesNotExist);
+        assert_err!(Infimum::submit_vote(RuntimeOrigin::signed(1), 0, shared_pk, message, Some(num_options)), Error::<Test>::VoteOptionOutOfRange);
+        assert_ok!(Infimum::submit_vote(RuntimeOrigin::signed(1), 0, shared_pk, message, Some(num_options - 1)));
+        assert_ok!(Infimum::submit_vote(RuntimeOrigin::signed(1), 0, shared_pk, message, None));
+
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 2);
+        assert_eq!(Infimum::interaction_count(0, shared_pk), 2);
+        assert_eq!(Infimum::interaction_count(0, pk), 0);
+    })
+}
+
+/// Polls which reject empty messages should refuse interactions consisting entirely of zeroes.
+#[test]
+fn participant_interaction_empty_message()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, ..) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { reject_empty_messages: true, ..get_poll_config_input() }));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, [[0u8; 32]; 10]), Error::<Test>::EmptyInteractionData);
+        assert_err!(Infimum::submit_vote(RuntimeOrigin::signed(1), 0, shared_pk, [[0u8; 32]; 10], None), Error::<Test>::EmptyInteractionData);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 1);
+    })
+}
+
+/// Participants may optionally be prevented from registering under the public key of the poll coordinator.
+#[test]
+fn participant_registration_coordinator_key()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (coordinator_pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), coordinator_pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        RejectCoordinatorKey::set(true);
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, coordinator_pk), Error::<Test>::CoordinatorKeyAsParticipant);
+
+        let (pk, _shared_pk, _message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        RejectCoordinatorKey::set(false);
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 0, coordinator_pk));
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count, 2);
+    })
+}
+
+/// Interactions may optionally be rejected if their ephemeral key was used to register in the poll.
+#[test]
+fn participant_interaction_ephemeral_key_reused()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        RejectKeyReuse::set(true);
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        let batch: PollInteractionBatch<Test> = vec::Vec::from([(shared_pk, message), (pk, message)]).try_into().unwrap();
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, pk, message), Error::<Test>::EphemeralKeyReused);
+        assert_err!(Infimum::submit_vote(RuntimeOrigin::signed(1), 0, pk, message, None), Error::<Test>::EphemeralKeyReused);
+        assert_err_ignore_postinfo!(Infimum::interact_batch(RuntimeOrigin::signed(1), 0, batch), Error::<Test>::EphemeralKeyReused);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+
+        RejectKeyReuse::set(false);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, pk, message));
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 2);
+    })
+}
+
+/// Interactions may optionally be rejected unless their key was used to register in the poll.
+#[test]
+fn participant_interaction_unregistered_key()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        let (origin, other_pk) = get_participants()[1];
+
+        // Registered keys are only recorded whilst either of the key rules is enabled.
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(origin), 0, other_pk));
+        assert!(!RegisteredKeys::<Test>::contains_key(0, other_pk));
+
+        RequireRegisteredKeys::set(true);
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        assert!(RegisteredKeys::<Test>::contains_key(0, pk));
+
+        run_to_block(1 + signup_period);
+
+        let batch: PollInteractionBatch<Test> = vec::Vec::from([(pk, message), (shared_pk, message)]).try_into().unwrap();
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::UnregisteredInteraction);
+        assert_err!(Infimum::submit_vote(RuntimeOrigin::signed(1), 0, shared_pk, message, None), Error::<Test>::UnregisteredInteraction);
+        assert_err_ignore_postinfo!(Infimum::interact_batch(RuntimeOrigin::signed(1), 0, batch), Error::<Test>::UnregisteredInteraction);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, pk, message));
+
+        // By default interactions are accepted under any key.
+        RequireRegisteredKeys::set(false);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 2);
+    })
+}
+
+/// Rejecting reused ephemeral keys and requiring registered keys together would reject every interaction.
+#[test]
+#[should_panic(expected = "may not both be enabled")]
+fn participant_interaction_key_rules_conflict()
+{
+    RejectKeyReuse::set(true);
+    <Infimum as Hooks<u64>>::integrity_test();
+
+    RequireRegisteredKeys::set(true);
+    <Infimum as Hooks<u64>>::integrity_test();
+}
+
+/// Upgrading from storage version 0 should clear the coordinator and poll entries, and only do so once.
+#[test]
+fn storage_upgrade_clears_v0_entries()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone()));
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), get_poll_config_input()));
+
+        StorageVersion::new(0).put::<Infimum>();
+        <Infimum as Hooks<u64>>::on_runtime_upgrade();
+
+        assert_eq!(Infimum::on_chain_storage_version(), StorageVersion::new(1));
+        assert_eq!(Infimum::coordinators(0), None);
+        assert_eq!(Infimum::polls(0), None);
+        assert!(Infimum::poll_ids(0).is_empty());
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        <Infimum as Hooks<u64>>::on_runtime_upgrade();
+        assert!(Infimum::coordinators(0).is_some());
+    })
+}
+
+/// The registration tree should be frozen once interactions are permitted.
+#[test]
+fn participant_registration_frozen()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(signup_period);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::PollRegistrationInProgress);
+        assert!(matches!(Infimum::polls(0).unwrap().consume_interaction(shared_pk, message), Err(MerkleTreeError::TreeFrozen)));
+
+        run_to_block(1 + signup_period);
+        let registrations = Infimum::polls(0).unwrap().state.registrations;
+
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 0, pk), Error::<Test>::PollRegistrationHasEnded);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 0, pk), Error::<Test>::PollRegistrationHasEnded);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations, registrations);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 2);
+
+        let poll = Infimum::polls(0).unwrap();
+        assert!(matches!(poll.register_participant(pk, 1 + signup_period), Err(MerkleTreeError::TreeFrozen)));
+
+        run_to_block(2 + signup_period + voting_period);
+        assert!(matches!(Infimum::polls(0).unwrap().consume_interaction(shared_pk, message), Err(MerkleTreeError::TreeFrozen)));
+    })
+}
+
+/// Participants should only be able to interact during the voting period.
+#[test]
+fn participant_interaction_outside_period()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::PollRegistrationInProgress);
+        run_to_block(2 + signup_period + voting_period);
+
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::PollVotingHasEnded);
+    })
+}
+
+/// The maximal number of allowable interactions should be enforced.
+#[test]
+fn participant_interaction_limit()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, _interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, 1, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        System::assert_last_event(Event::PollInteraction { poll_id: 0, count: 4, depth: 0, public_key: shared_pk, data: message }.into());
+
+        // The final interaction fills the interaction tree.
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        System::assert_has_event(Event::PollInteraction { poll_id: 0, count: 5, depth: 1, public_key: shared_pk, data: message }.into());
+        System::assert_last_event(Event::PollStateTreeFilled { poll_id: 0, phase: MergePhase::Interaction }.into());
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::ParticipantInteractionLimitReached);
+    })
+}
+
+/// The interaction tree of a poll should grow to accommodate turnout up to its maximum depth.
+#[test]
+fn participant_interaction_tree_growth()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, ..) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_err!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { interaction_depth: 2, max_interaction_depth: Some(1), ..get_poll_config_input() }), Error::<Test>::PollConfigInvalid);
+        assert_err!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { interaction_depth: 1, max_interaction_depth: Some(5), ..get_poll_config_input() }), Error::<Test>::PollConfigInvalid);
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { interaction_depth: 1, max_interaction_depth: Some(2), ..get_poll_config_input() }));
+        assert_eq!(Infimum::polls(0).unwrap().config.max_interactions, 25);
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        for _ in 0..6
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        }
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.full_depth, 2);
+
+        for _ in 6..25
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        }
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::ParticipantInteractionLimitReached);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 25);
+    })
+}
+
+/// Interaction events should report the depth of the interaction tree as it fills.
+#[test]
+fn participant_interaction_depth()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, _interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, 2, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        for _ in 0..25
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        }
+
+        let depths: vec::Vec<(u32, u8)> = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::Infimum(Event::PollInteraction { count, depth, .. }) => Some((count, depth)),
+                _ => None
+            })
+            .collect();
+
+        // The depth increases as each level of the quinary interaction tree is filled.
+        let expected: vec::Vec<(u32, u8)> = (1..=25)
+            .map(|count| (count, if count == 25 { 2 } else if count >= 5 { 1 } else { 0 }))
+            .collect();
+        assert_eq!(depths, expected);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.depth, 2);
+    })
+}
+
+/// A batch of interactions should be inserted up to the interaction limit, discarding the remainder.
+#[test]
+fn participant_interact_batch()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, _interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, 1, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        let empty: PollInteractionBatch<Test> = vec::Vec::new().try_into().unwrap();
+        let batch: PollInteractionBatch<Test> = vec::Vec::from([(shared_pk, message); 3]).try_into().unwrap();
+        let excess_batch: PollInteractionBatch<Test> = vec::Vec::from([(shared_pk, message); 4]).try_into().unwrap();
+
+        assert_err_ignore_postinfo!(Infimum::interact_batch(RuntimeOrigin::signed(1), 0, batch.clone()), Error::<Test>::PollRegistrationInProgress);
+
+        run_to_block(1 + signup_period);
+        assert_err_ignore_postinfo!(Infimum::interact_batch(RuntimeOrigin::signed(1), 0, empty), Error::<Test>::MalformedInput);
+
+        assert_eq!(
+            Infimum::interact_batch(RuntimeOrigin::signed(1), 0, batch.clone()),
+            Ok(Some(Infimum::interact_batch_weight(3)).into())
+        );
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 3);
+        System::assert_last_event(Event::PollInteraction { poll_id: 0, count: 3, depth: 0, public_key: shared_pk, data: message }.into());
+
+        // Only the first two messages fit within the interaction limit.
+        assert_eq!(
+            Infimum::interact_batch(RuntimeOrigin::signed(1), 0, excess_batch),
+            Ok(Some(Infimum::interact_batch_weight(2)).into())
+        );
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 5);
+        assert_eq!(Infimum::interaction_count(0, shared_pk), 5);
+        System::assert_has_event(Event::PollInteraction { poll_id: 0, count: 5, depth: 1, public_key: shared_pk, data: message }.into());
+        System::assert_last_event(Event::PollStateTreeFilled { poll_id: 0, phase: MergePhase::Interaction }.into());
+
+        assert_err_ignore_postinfo!(Infimum::interact_batch(RuntimeOrigin::signed(1), 0, batch), Error::<Test>::ParticipantInteractionLimitReached);
+    })
+}
+
+/// The registration tree should only be mergable after the signup period.
+#[test]
+fn merge_registration_signup_period()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollRegistrationInProgress);
+    })
+}
+
+/// The interaction tree should only be mergable after the voting period.
+#[test]
+fn merge_interaction_voting_period()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(2 + signup_period);
+
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollVotingInProgress);
+    })
+}
+
+/// The registration tree may only be merged once a block has elapsed after the final block of the registration period.
+#[test]
+fn merge_registration_settling_gap()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        // Register in the final block of the registration period.
+        run_to_block(signup_period);
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+        let preview = Infimum::preview_registration_root(0);
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollRegistrationInProgress);
+
+        run_to_block(2 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count, get_participants().len() as u32);
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.root, preview);
+    })
+}
+
+/// The registration tree should be able to be merged and produce the correct root and commitment value.
+#[test]
+fn merge_registration_state_success()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+        
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        assert_eq!(
+            Infimum::polls(0).unwrap().state.registrations.root, 
+            Some([16, 44, 202, 10, 154, 154, 255, 162, 164, 69, 231, 62, 33, 104, 15, 112, 88, 216, 113, 111, 70, 122, 146, 189, 80, 94, 79, 213, 137, 73, 176, 205])
+        );
+        assert_eq!(
+            Infimum::polls(0).unwrap().state.commitment.process,
+            (0, [42, 172, 65, 18, 133, 85, 171, 69, 236, 46, 172, 46, 31, 229, 218, 229, 163, 201, 108, 165, 174, 141, 40, 17, 128, 246, 71, 216, 46, 235, 135, 32])
+        );
+    })
+}
+
+/// The registration leaves should be recoverable from the `ParticipantRegistered` event data alone.
+#[test]
+fn registration_leaf_from_event()
+{
+    new_test_ext().execute_with(|| {
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        let leaves: vec::Vec<HashBytes> = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::Infimum(Event::ParticipantRegistered { public_key, block, .. }) => Some(registration_leaf(&public_key, 1, block).unwrap()),
+                _ => None
+            })
+            .collect();
+
+        assert_eq!(leaves.len(), 3);
+        assert_eq!(
+            leaves[0],
+            [24, 116, 220, 169, 163, 203, 155, 60, 214, 138, 128, 220, 78, 194, 18, 47, 52, 174, 174, 15, 149, 160, 247, 234, 65, 201, 39, 198, 20, 181, 240, 133]
+        );
+
+        // The first leaf of the registration tree is reserved for the zero leaf.
+        let mut hasher = Poseidon::<Fr>::new_circom(2).unwrap();
+        let left = hasher.hash_bytes_be(&[&BINARY_ZEROES[0], &leaves[0]]).unwrap();
+        let right = hasher.hash_bytes_be(&[&leaves[1], &leaves[2]]).unwrap();
+        let root = hasher.hash_bytes_be(&[&left, &right]).unwrap();
+
+        assert_eq!(Infimum::preview_registration_root(0), Some(root));
+        assert_eq!(
+            root,
+            [16, 44, 202, 10, 154, 154, 255, 162, 164, 69, 231, 62, 33, 104, 15, 112, 88, 216, 113, 111, 70, 122, 146, 189, 80, 94, 79, 213, 137, 73, 176, 205]
+        );
+    })
+}
+
+/// The interaction leaves should be recoverable from the `PollInteraction` event data alone.
+#[test]
+fn interaction_leaf_from_event()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+
+        let leaves: vec::Vec<HashBytes> = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::Infimum(Event::PollInteraction { public_key, data, .. }) => Some(interaction_leaf(&public_key, &data).unwrap()),
+                _ => None
+            })
+            .collect();
+
+        assert_eq!(leaves, vec::Vec::from([interaction_leaf(&shared_pk, &message).unwrap()]));
+        assert_eq!(
+            leaves[0],
+            [17, 227, 242, 122, 28, 83, 74, 247, 98, 202, 135, 226, 42, 94, 247, 78, 190, 144, 142, 101, 232, 193, 55, 62, 162, 125, 60, 140, 88, 92, 250, 160]
+        );
+    })
+}
+
+/// A registration tree with a single registration should merge to the root of the zero leaf and the registration.
+#[test]
+fn merge_single_registration()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        run_to_block(2);
+
+        let (origin, pk) = get_participants()[0];
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(origin), 0, pk));
+
+        run_to_block(2 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let mut hasher = Poseidon::<Fr>::new_circom(2).unwrap();
+        let leaf = registration_leaf(&pk, 1, 2).unwrap();
+        let root = hasher.hash_bytes_be(&[&BINARY_ZEROES[0], &leaf]).unwrap();
+
+        let registrations = Infimum::polls(0).unwrap().state.registrations;
+        assert_eq!(registrations.count, 1);
+        assert_eq!(registrations.depth, 1);
+        assert_eq!(registrations.root, Some(root));
+        assert_eq!(Infimum::registration_state_depth(0), Some(1));
+    })
+}
+
+/// The registration tree should be able to be merged and produce the correct root and expected number of proofs.
+#[test]
+fn merge_interaction_state_success()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let registration_root = Infimum::polls(0).unwrap().state.registrations.root;
+        System::assert_last_event(Event::PollStateMerged {
+            poll_id: 0,
+            phase: MergePhase::Registration,
+            registration_root,
+            interaction_root: None,
+            registration_count: 3,
+            registration_depth: 2,
+            interaction_count: 0,
+            interaction_depth: 2
+        }.into());
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        assert_eq!(
+            Infimum::polls(0).unwrap().state.interactions.root, 
+            Some([31, 254, 7, 234, 211, 75, 174, 138, 104, 42, 237, 212, 221, 158, 115, 172, 29, 63, 109, 91, 47, 88, 77, 75, 76, 5, 201, 65, 69, 119, 219, 182])
+        );
+        System::assert_last_event(Event::PollStateMerged {
+            poll_id: 0,
+            phase: MergePhase::Interaction,
+            registration_root,
+            interaction_root: Infimum::polls(0).unwrap().state.interactions.root,
+            registration_count: 3,
+            registration_depth: 2,
+            interaction_count: 1,
+            interaction_depth: 2
+        }.into());
+
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.expected_process, 1);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.expected_tally, 2);
+    })
+}
+
+/// The next merge phase should follow the state trees which remain to be merged.
+#[test]
+fn next_merge_phase()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_eq!(Infimum::next_merge_phase(0), None);
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_eq!(Infimum::next_merge_phase(0), Some(MergePhase::Registration));
+
+        run_to_block(2);
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(2 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_eq!(Infimum::next_merge_phase(0), Some(MergePhase::Interaction));
+
+        let (_pk, shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message_data));
+
+        // The phase is unchanged by a rejected merge.
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollVotingInProgress);
+        assert_eq!(Infimum::next_merge_phase(0), Some(MergePhase::Interaction));
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_eq!(Infimum::next_merge_phase(0), None);
+    })
+}
+
+/// Merging should only be charged for the hashes it computes, as estimated by `pending_merge_cost`, including when
+/// the merge is rejected.
+#[test]
+fn merge_poll_state_actual_weight()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, ..) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), get_poll_config_input()));
+
+        // Rejections prior to the merge are only charged for the storage reads.
+        assert_eq!(
+            Infimum::merge_poll_state(RuntimeOrigin::signed(0)),
+            Err(Error::<Test>::PollRegistrationInProgress.with_weight(Infimum::merge_poll_state_weight(0)))
+        );
+
+        run_to_block(2);
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(2 + signup_period);
+
+        // A rejected merge is charged for the hashes it may have computed.
+        let poll = Infimum::polls(0).unwrap();
+        Polls::<Test>::mutate(0, |poll| {
+            poll.as_mut().unwrap().state.registrations.hashes = vec::Vec::from([ (0, [1u8; 32]), (0, [2u8; 32]), (0, [3u8; 32]) ]);
+        });
+        let hashes = Infimum::pending_merge_cost(0).unwrap();
+        assert_eq!(hashes, 1);
+        assert_eq!(
+            Infimum::merge_poll_state(RuntimeOrigin::signed(0)),
+            Err(Error::<Test>::PollMergeFailed { reason: MerkleTreeError::SUBTREE_OVERSIZED }.with_weight(Infimum::merge_poll_state_weight(hashes)))
+        );
+        Polls::<Test>::insert(0, poll);
+
+        let hashes = Infimum::pending_merge_cost(0).unwrap();
+        let info = Infimum::merge_poll_state(RuntimeOrigin::signed(0)).unwrap();
+        assert_eq!(info.actual_weight, Some(Infimum::merge_poll_state_weight(hashes)));
+
+        let (_pk, shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message_data));
+
+        run_to_block(2 + signup_period + voting_period);
+        let hashes = Infimum::pending_merge_cost(0).unwrap();
+        assert_eq!(hashes, 2);
+        let info = Infimum::merge_poll_state(RuntimeOrigin::signed(0)).unwrap();
+        assert_eq!(info.actual_weight, Some(Infimum::merge_poll_state_weight(hashes)));
+    })
+}
+
+/// The correct public signals should be produced prior to proving.
+#[test]
+fn process_messages_public_signals()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_eq!(Infimum::registration_state_depth(0), None);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // Assert validity of public proof inputs. Expected:
+        // [
+        //     "4",
+        //     "25",
+        //     "14470532103638942535012694444804587998397771818472334093141391431267230669750",
+        //     "2",
+        //     "1",
+        //     "0",
+        //     "19920653097131876015283340295735326298336825292385683485447270132525802217807",
+        //     "19301486448472428800803584456730803281486402183229406170295981014011957970720",
+        //     "16572773172956595804863373620708467606178628870321168598749359785398426610593"
+        // ]
+        // The ordering of the inputs is asserted by `process_messages_public_input_order`.
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count + 1, 4);
+        assert_eq!(Infimum::polls(0).unwrap().get_voting_period_end(), 25);
+        assert_eq!(Infimum::voting_period_end(0), Some(1 + signup_period + voting_period));
+        assert_eq!(Infimum::voting_period_end(1), None);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.root, Some([31, 254, 7, 234, 211, 75, 174, 138, 104, 42, 237, 212, 221, 158, 115, 172, 29, 63, 109, 91, 47, 88, 77, 75, 76, 5, 201, 65, 69, 119, 219, 182]));
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.depth, 2);
+        assert_eq!(Infimum::registration_state_depth(0), Some(2));
+        let mut hasher = Poseidon::<Fr>::new_circom(2).unwrap();
+        let coord_pub_key_fr: vec::Vec<Fr> = vec::Vec::from([ alice_pk.x, alice_pk.y ])
+            .iter()
+            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
+            .collect();
+        let coord_pub_key_hash = hasher.hash(&coord_pub_key_fr).unwrap().into_bigint().to_string();
+        assert_eq!(coord_pub_key_hash, "19920653097131876015283340295735326298336825292385683485447270132525802217807");
+        assert_eq!(
+            Infimum::polls(0).unwrap().state.commitment.process,
+            (0, [42, 172, 65, 18, 133, 85, 171, 69, 236, 46, 172, 46, 31, 229, 218, 229, 163, 201, 108, 165, 174, 141, 40, 17, 128, 246, 71, 216, 46, 235, 135, 32])
+        );
+    })
+}
+
+/// The message processing public inputs should be supplied in the order the circuit declares its public signals.
+#[test]
+fn process_messages_public_input_order()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_proof_data, new_proof_commitment, _tpf, _tally_commitment) = get_proof();
+        let process_commitment = Infimum::polls(0).unwrap().state.commitment.process.1;
+        let inputs = Infimum::proof_public_inputs(0, 0, process_commitment, new_proof_commitment).unwrap();
+
+        let expected = [
+            // The number of registrations, including the blank registration.
+            "4",
+            // The final block of the voting period.
+            "25",
+            // The root of the interaction tree.
+            "14470532103638942535012694444804587998397771818472334093141391431267230669750",
+            // The depth of the registration tree.
+            "2",
+            // The end index of the batch of interactions.
+            "1",
+            // The start index of the batch of interactions.
+            "0",
+            // The hash of the coordinator public key.
+            "19920653097131876015283340295735326298336825292385683485447270132525802217807",
+            // The current process commitment.
+            "19301486448472428800803584456730803281486402183229406170295981014011957970720",
+            // The new process commitment.
+            "16572773172956595804863373620708467606178628870321168598749359785398426610593"
+        ];
+
+        assert_eq!(inputs.len(), expected.len());
+        for (index, input) in inputs.iter().enumerate()
+        {
+            assert_eq!(Fr::from_be_bytes_mod_order(input).into_bigint().to_string(), expected[index], "public input {}", index);
+        }
+    })
+}
+
+/// A single valid message processing proof should be successfully verifiable.
+#[test]
+fn commit_outcome_single_batch()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+    
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None));
+    
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, new_proof_commitment));
+    })
+}
+
+/// Batches verified via `verify_outcome_batches` should be verified without advancing the poll state.
+#[test]
+fn verify_outcome_batches()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+        let initial_commitment = Infimum::polls(0).unwrap().state.commitment;
+
+        assert_ok!(Infimum::verify_outcome_batches(RuntimeOrigin::signed(0), proof_batches.clone(), None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment, initial_commitment);
+
+        let mut verified_commitment = initial_commitment.clone();
+        verified_commitment.process = (1, new_proof_commitment);
+        System::assert_last_event(Event::PollProofVerified { poll_id: 0, commitment: verified_commitment }.into());
+
+        // The same batch may subsequently be committed.
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, new_proof_commitment));
+    })
+}
+
+/// The previewed registration root should match the root computed by the merge.
+#[test]
+fn preview_registration_root()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_eq!(Infimum::preview_registration_root(0), None);
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(2 + signup_period);
+        let preview = Infimum::preview_registration_root(0);
+        assert!(preview.is_some());
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.root, None);
+
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.root, preview);
+        assert_eq!(Infimum::preview_registration_root(0), preview);
+    })
+}
+
+/// The countdown should report the blocks remaining in the registration and voting periods.
+#[test]
+fn poll_countdown()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_eq!(Infimum::poll_countdown(0), None);
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        assert_eq!(Infimum::poll_countdown(0), Some((PollStatus::Registration, signup_period)));
+
+        run_to_block(signup_period);
+        assert_eq!(Infimum::poll_countdown(0), Some((PollStatus::Registration, 1)));
+
+        run_to_block(1 + signup_period);
+        assert_eq!(Infimum::poll_countdown(0), Some((PollStatus::Voting, voting_period + 1)));
+
+        let (_, shared_pk, message) = get_participant();
+        run_to_block(1 + signup_period + voting_period);
+        assert_eq!(Infimum::poll_countdown(0), Some((PollStatus::Voting, 1)));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_eq!(Infimum::poll_countdown(0), None);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::PollVotingHasEnded);
+    })
+}
+
+/// Polls should be enumerable by their derived status, across coordinators and in pages.
+#[test]
+fn polls_by_status()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        for coordinator in 0..6
+        {
+            assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(coordinator), pk, vk.clone()));
+        }
+        for coordinator in 0..4
+        {
+            assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(coordinator), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+        }
+        assert_eq!(Infimum::polls_by_status(PollStatus::Registration, 0, 10), (vec::Vec::from([0, 1, 2, 3]), None));
+        assert_eq!(Infimum::polls_by_status(PollStatus::Voting, 0, 10), (vec::Vec::<PollId>::new(), None));
+
+        // Each of the polls has ended; one is nullified and one has a committed outcome.
+        let ended_at = 1 + signup_period + voting_period + 1;
+        run_to_block(ended_at);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        Polls::<Test>::mutate(1, |poll| {
+            poll.as_mut().unwrap().state.outcome = Some(0);
+        });
+
+        // A poll which has progressed to its voting period, and a poll which is accepting registrations.
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(4), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+        run_to_block(ended_at + signup_period);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(5), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        assert_eq!(Infimum::polls_by_status(PollStatus::Nullified, 0, 10), (vec::Vec::from([0]), None));
+        assert_eq!(Infimum::polls_by_status(PollStatus::Finalized, 0, 10), (vec::Vec::from([1]), None));
+        assert_eq!(Infimum::polls_by_status(PollStatus::Tallying, 0, 10), (vec::Vec::from([2, 3]), None));
+        assert_eq!(Infimum::polls_by_status(PollStatus::Voting, 0, 10), (vec::Vec::from([4]), None));
+        assert_eq!(Infimum::polls_by_status(PollStatus::Registration, 0, 10), (vec::Vec::from([5]), None));
+
+        // Results are paginated by a starting cursor and the maximum number of polls visited, such that a page may be empty.
+        assert_eq!(Infimum::polls_by_status(PollStatus::Tallying, 0, 2), (vec::Vec::<PollId>::new(), Some(2)));
+        assert_eq!(Infimum::polls_by_status(PollStatus::Tallying, 2, 2), (vec::Vec::from([2, 3]), Some(4)));
+        assert_eq!(Infimum::polls_by_status(PollStatus::Tallying, 4, 2), (vec::Vec::<PollId>::new(), None));
+        assert_eq!(Infimum::polls_by_status(PollStatus::Tallying, 3, 1), (vec::Vec::from([3]), Some(4)));
+        assert_eq!(Infimum::polls_by_status(PollStatus::Tallying, 6, 10), (vec::Vec::<PollId>::new(), None));
+        assert_eq!(Infimum::polls_by_status(PollStatus::Tallying, 0, 0), (vec::Vec::<PollId>::new(), Some(0)));
+    })
+}
+
+/// The initial process commitment should match the commitment seeded by the registration merge.
+#[test]
+fn initial_process_commitment()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_eq!(Infimum::initial_process_commitment(0), None);
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(2 + signup_period);
+        assert_eq!(Infimum::initial_process_commitment(0), None);
+
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_eq!(
+            Infimum::initial_process_commitment(0),
+            Some(Infimum::polls(0).unwrap().state.commitment.process.1)
+        );
+    })
+}
+
+/// Public inputs for pending proofs should match those supplied to the verifier.
+#[test]
+fn proof_public_inputs()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        let (proof_data, new_proof_commitment, _tpf, tally_commitment) = get_proof();
+        let process_commitment = Infimum::polls(0).unwrap().state.commitment.process.1;
+
+        run_to_block(26);
+        assert_eq!(Infimum::proof_public_inputs(0, 0, process_commitment, new_proof_commitment), None);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let inputs = Infimum::proof_public_inputs(0, 0, process_commitment, new_proof_commitment).unwrap();
+        assert_eq!(inputs.len(), 9);
+        assert_eq!(inputs[7], process_commitment);
+        assert_eq!(inputs[8], new_proof_commitment);
+
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None));
+
+        let inputs = Infimum::proof_public_inputs(0, 1, [0u8; 32], tally_commitment).unwrap();
+        assert_eq!(inputs.len(), 5);
+        assert_eq!(inputs[0], new_proof_commitment);
+        assert_eq!(inputs[2], tally_commitment);
+        assert_eq!(Infimum::proof_public_inputs(1, 0, process_commitment, new_proof_commitment), None);
+    })
+}
+
+/// Batch indices should be computed without overflow for subtrees whose size exceeds a u8.
+#[test]
+fn proof_public_inputs_deep_subtree()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let to_bytes = |n: u32| -> [u8; 32] { Fr::from(n).into_bigint().to_bytes_be().try_into().unwrap() };
+
+        // A quinary subtree of depth four holds 625 interactions.
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { interaction_depth: 4, process_subtree_depth: 4, ..get_poll_config_input() }));
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, shared_pk, message) = get_participant();
+        for _ in 0..6
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        }
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.expected_process, 1);
+
+        // Every interaction is processed by a single batch spanning [0, 6).
+        let inputs = Infimum::proof_public_inputs(0, 0, [0u8; 32], [0u8; 32]).unwrap();
+        assert_eq!(inputs[4], to_bytes(6));
+        assert_eq!(inputs[5], to_bytes(0));
+    })
+}
+
+/// The vote overriding flag should be supplied to the message processing circuit, for circuit revisions which declare it.
+#[test]
+fn proof_public_inputs_allow_override()
+{
+    let public_inputs = |allow_override: Option<bool>| new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+
+        // Keys generated for the reference circuit may not verify polls which forbid overriding.
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk.clone()));
+        assert_err!(
+            Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { allow_override: Some(false), ..get_poll_config_input() }),
+            Error::<Test>::PollConfigInvalid
+        );
+
+        assert_ok!(Infimum::rotate_verify_key(RuntimeOrigin::signed(0), VerifyingKeys { circuit_version: ALLOW_OVERRIDE_CIRCUIT_VERSION, ..alice_vk }));
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { allow_override, ..get_poll_config_input() }));
+        assert_eq!(Infimum::polls(0).unwrap().config.allow_override, allow_override.unwrap_or(true));
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_proof_data, new_proof_commitment, _tpf, _tally_commitment) = get_proof();
+        let process_commitment = Infimum::polls(0).unwrap().state.commitment.process.1;
+
+        Infimum::proof_public_inputs(0, 0, process_commitment, new_proof_commitment).unwrap()
+    });
+
+    // The flag defaults to permitting overriding.
+    let overridable = public_inputs(None);
+    assert_eq!(overridable, public_inputs(Some(true)));
+
+    // The input vector has the same length regardless of the flag, and differs only in the flag itself.
+    let one_shot = public_inputs(Some(false));
+    assert_eq!(overridable.len(), 10);
+    assert_eq!(one_shot.len(), 10);
+    assert_eq!(one_shot[..9], overridable[..9]);
+    assert_eq!(overridable[9][31], 1);
+    assert_eq!(one_shot[9], [0u8; 32]);
+}
+
+/// Proofs should be verifiable against keys which are not registered by any coordinator.
+#[test]
+fn verify_proof_with_key()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (_malformed_pk, malformed_vk) = get_coordinator_data_malformed();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk.clone()));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (proof_data, new_proof_commitment, _tpf, _tally_commitment) = get_proof();
+        let process_commitment = Infimum::polls(0).unwrap().state.commitment.process.1;
+        let inputs = Infimum::proof_public_inputs(0, 0, process_commitment, new_proof_commitment).unwrap();
+
+        assert!(Infimum::verify_proof_with_key(alice_vk.process.clone(), inputs.clone(), proof_data.clone()));
+        assert!(!Infimum::verify_proof_with_key(alice_vk.tally, inputs.clone(), proof_data.clone()));
+        assert!(!Infimum::verify_proof_with_key(malformed_vk.process, inputs, proof_data));
+
+        // Verification does not advance the poll state.
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.0, 0);
+    })
+}
+
+/// An invalid message processing proof should be rejected.
+#[test]
+fn commit_outcome_invalid_proof()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pf, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_data = ProofData {
+            pi_a: vec::Vec::from([ 1, 90, 132, 178, 53, 72, 162, 190, 174, 234, 202, 225, 124, 15, 203, 241, 24, 166, 28, 140, 33, 166, 32, 142, 98, 204, 176, 252, 230, 140, 192, 20, 139, 39, 230, 152, 184, 129, 60, 181, 238, 20, 200, 162, 172, 120, 43, 154, 8, 140, 169, 102, 4, 146, 94, 64, 88, 220, 77, 63, 11, 46, 20, 23 ]),
+            pi_b: vec::Vec::from([ 84, 30, 183, 52, 30, 16, 193, 22, 207, 118, 249, 89, 64, 160, 107, 10, 205, 244, 52, 202, 249, 228, 234, 172, 175, 156, 23, 220, 186, 234, 66, 12, 83, 150, 12, 48, 176, 8, 107, 225, 135, 4, 133, 97, 30, 180, 200, 113, 196, 162, 63, 247, 68, 183, 181, 125, 165, 1, 27, 178, 151, 4, 100, 27, 235, 67, 144, 49, 36, 228, 17, 171, 138, 32, 78, 235, 17, 96, 110, 90, 181, 238, 134, 153, 143, 241, 126, 140, 110, 231, 89, 76, 11, 204, 229, 24, 29, 255, 158, 244, 198, 108, 64, 92, 228, 96, 63, 226, 6, 159, 93, 250, 157, 181, 97, 183, 8, 78, 34, 241, 253, 29, 119, 62, 9, 19, 207, 164 ]),
+            pi_c: vec::Vec::from([ 182, 96, 48, 82, 178, 199, 89, 110, 195, 62, 134, 21, 179, 247, 238, 14, 188, 181, 110, 68, 123, 104, 180, 13, 224, 126, 126, 197, 175, 15, 10, 21, 13, 52, 132, 172, 241, 121, 20, 152, 135, 139, 30, 106, 85, 16, 123, 212, 179, 189, 37, 237, 139, 45, 248, 83, 70, 14, 234, 82, 234, 229, 157, 8 ])
+        };
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+    
+        assert_err_ignore_postinfo!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::MalformedProof { reason: VerifyError::PROOF_MALFORMED });
+    })
+}
+
+/// Weight for batches which were not verified should be refunded when a proof is rejected.
+#[test]
+fn commit_outcome_refund_weight()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pf, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_data = ProofData {
+            pi_a: vec::Vec::from([ 1, 90, 132, 178, 53, 72, 162, 190, 174, 234, 202, 225, 124, 15, 203, 241, 24, 166, 28, 140, 33, 166, 32, 142, 98, 204, 176, 252, 230, 140, 192, 20, 139, 39, 230, 152, 184, 129, 60, 181, 238, 20, 200, 162, 172, 120, 43, 154, 8, 140, 169, 102, 4, 146, 94, 64, 88, 220, 77, 63, 11, 46, 20, 23 ]),
+            pi_b: vec::Vec::from([ 84, 30, 183, 52, 30, 16, 193, 22, 207, 118, 249, 89, 64, 160, 107, 10, 205, 244, 52, 202, 249, 228, 234, 172, 175, 156, 23, 220, 186, 234, 66, 12, 83, 150, 12, 48, 176, 8, 107, 225, 135, 4, 133, 97, 30, 180, 200, 113, 196, 162, 63, 247, 68, 183, 181, 125, 165, 1, 27, 178, 151, 4, 100, 27, 235, 67, 144, 49, 36, 228, 17, 171, 138, 32, 78, 235, 17, 96, 110, 90, 181, 238, 134, 153, 143, 241, 126, 140, 110, 231, 89, 76, 11, 204, 229, 24, 29, 255, 158, 244, 198, 108, 64, 92, 228, 96, 63, 226, 6, 159, 93, 250, 157, 181, 97, 183, 8, 78, 34, 241, 253, 29, 119, 62, 9, 19, 207, 164 ]),
+            pi_c: vec::Vec::from([ 182, 96, 48, 82, 178, 199, 89, 110, 195, 62, 134, 21, 179, 247, 238, 14, 188, 181, 110, 68, 123, 104, 180, 13, 224, 126, 126, 197, 175, 15, 10, 21, 13, 52, 132, 172, 241, 121, 20, 152, 135, 139, 30, 106, 85, 16, 123, 212, 179, 189, 37, 237, 139, 45, 248, 83, 70, 14, 234, 82, 234, 229, 157, 8 ])
+        };
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([
+            (proof_data.clone(), new_proof_commitment),
+            (proof_data, new_proof_commitment)
+        ]);
+
+        assert_eq!(
+            Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None),
+            Err(Error::<Test>::MalformedProof { reason: VerifyError::PROOF_MALFORMED }.with_weight(Infimum::commit_outcome_weight(1)))
+        );
+    })
+}
+
+/// An valid message processing or tally proof with an invalid commitment should be rejected.
+#[test]
+fn commit_outcome_invalid_commitment()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (proof_data, process_commitment, tally_proof_data, _tc) = get_proof();
+        let new_proof_commitment: HashBytes = [1, 191, 85, 98, 25, 92, 104, 227, 66, 252, 50, 63, 42, 27, 108, 81, 67, 38, 115, 38, 128, 126, 14, 99, 203, 194, 61, 124, 1, 119, 164, 65];
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data.clone(), new_proof_commitment)]);
+    
+        assert_err_ignore_postinfo!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::MalformedProof { reason: VerifyError::PROOF_REJECTED });
+
+        // A tally proof which does not continue the tally chain is reported alike by both entry points.
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), vec::Vec::from([(proof_data, process_commitment)]), None));
+        let tally_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(tally_proof_data, new_proof_commitment)]);
+
+        assert_err_ignore_postinfo!(Infimum::commit_outcome(RuntimeOrigin::signed(0), tally_batches.clone(), None), Error::<Test>::TallyCommitmentMismatch);
+        assert_err_ignore_postinfo!(
+            Infimum::commit_outcome_from(RuntimeOrigin::signed(0), 1, process_commitment, tally_batches, None),
+            Error::<Test>::TallyCommitmentMismatch
+        );
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally.0, 0);
+    })
+}
+
+/// A valid message processing proof with mismatched data should be rejected.
+#[test]
+fn commit_outcome_mismatched_state()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
+
+        run_to_block(2);
+
+        let (_, bob_pk) = get_participants()[1];
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, bob_pk));
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (pk, shared_pk, message) = get_participant();
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        let (_pk, bob_shared_pk, message_data) = get_participant();
 
-        run_to_block(1 + signup_period);
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
-        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::ParticipantInteractionLimitReached);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+    
+        assert_err_ignore_postinfo!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::MalformedProof { reason: VerifyError::PROOF_REJECTED });
     })
 }
 
-/// The registration tree should only be mergable after the signup period.
+/// A valid tally proof should successfully chain to a valid message processing proof.
 #[test]
-fn merge_registration_signup_period()
+fn commit_outcome_full_batch()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -433,13 +3041,28 @@ fn merge_registration_signup_period()
             assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
         }
 
-        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollRegistrationInProgress);
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(process_proof_data, process_commitment), (tally_proof_data, tally_commitment)]);
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, process_commitment));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally, (1, tally_commitment));
     })
 }
 
-/// The interaction tree should only be mergable after the voting period.
+/// Process proofs should be rejected once every expected process proof has been verified, selecting the tally circuit instead.
 #[test]
-fn merge_interaction_voting_period()
+fn commit_outcome_process_tally_transition()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -469,16 +3092,321 @@ fn merge_interaction_voting_period()
             assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
         }
 
-        run_to_block(1 + signup_period);
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let process_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(process_proof_data, process_commitment)]);
+        let tally_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(tally_proof_data, tally_commitment)]);
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), process_batches.clone(), None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, process_commitment));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally.0, 0);
+        System::assert_last_event(Event::PollCommitmentUpdated {
+            poll_id: 0,
+            phase: CommitmentPhase::Process,
+            index: 1,
+            value: process_commitment,
+            commitment: Infimum::polls(0).unwrap().state.commitment
+        }.into());
+
+        // Every process proof has been verified, so the repeated batch is verified as a tally proof.
+        assert_err_ignore_postinfo!(Infimum::commit_outcome(RuntimeOrigin::signed(0), process_batches, None), Error::<Test>::TallyCommitmentMismatch);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, process_commitment));
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), tally_batches, None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, process_commitment));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally, (1, tally_commitment));
+        System::assert_last_event(Event::PollCommitmentUpdated {
+            poll_id: 0,
+            phase: CommitmentPhase::Tally,
+            index: 1,
+            value: tally_commitment,
+            commitment: Infimum::polls(0).unwrap().state.commitment
+        }.into());
+    })
+}
+
+/// The batches verified prior to a rejected batch should be committed, and the remaining batches discarded.
+#[test]
+fn commit_outcome_rejected_batch_retains_progress()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // The repeated process batch is rejected, so the trailing tally batch is discarded.
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([
+            (process_proof_data.clone(), process_commitment),
+            (process_proof_data, process_commitment),
+            (tally_proof_data.clone(), tally_commitment)
+        ]);
+
+        assert_eq!(
+            Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None),
+            Ok(Some(Infimum::commit_outcome_weight(2)).into())
+        );
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, process_commitment));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally.0, 0);
+        System::assert_has_event(Event::PollCommitmentUpdated {
+            poll_id: 0,
+            phase: CommitmentPhase::Process,
+            index: 1,
+            value: process_commitment,
+            commitment: Infimum::polls(0).unwrap().state.commitment
+        }.into());
+        System::assert_last_event(Event::PollProofBatchRejected {
+            poll_id: 0,
+            accepted: 1,
+            error: Error::<Test>::TallyCommitmentMismatch.into()
+        }.into());
+
+        // Only the remaining batches need to be resubmitted.
+        let tally_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(tally_proof_data, tally_commitment)]);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), tally_batches, None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally, (1, tally_commitment));
+    })
+}
+
+/// A partial chain of valid proofs should be successfully verified, but not produce an outcome.
+#[test]
+fn commit_outcome_partial_success()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                signup_period,
+                voting_period,
+                registration_depth,
+                interaction_depth,
+                process_subtree_depth,
+                tally_subtree_depth,
+                vote_option_tree_depth,
+                vote_options
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(process_proof_data, process_commitment), (tally_proof_data, tally_commitment)]);
+        let scenario = get_poll_scenario(0);
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, scenario.outcome));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, process_commitment));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally, (1, tally_commitment));
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
+    })
+}
+
+/// An audit summary of the poll should be recorded once its outcome is verified, and survive pruning.
+#[test]
+fn commit_outcome_records_finalized_poll()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let scenario = get_poll_scenario(0);
+
+        // No summary is recorded until the outcome is verified.
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), vec::Vec::from([(process_proof_data, process_commitment)]), None));
+        assert_eq!(Infimum::finalized_poll(0), None);
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), vec::Vec::from([(tally_proof_data, tally_commitment)]), scenario.outcome));
+
+        let poll = Infimum::polls(0).unwrap();
+        let summary = Infimum::finalized_poll(0).unwrap();
+        assert_eq!(summary.coordinator, 0);
+        assert_eq!(summary.coordinator_key, alice_pk);
+        assert_eq!(summary.created_at, 1);
+        assert_eq!(summary.config, poll.config);
+        assert_eq!(summary.depths, (poll.state.registrations.full_depth, poll.state.interactions.full_depth));
+        assert_eq!(summary.counts, (get_participants().len() as u32, 1));
+        assert_eq!(summary.registration_root, poll.state.registrations.root);
+        assert_eq!(summary.interaction_root, poll.state.interactions.root);
+        assert_eq!(summary.commitment, poll.state.commitment);
+        assert_eq!(Some(summary.outcome), scenario.expected);
+        assert_eq!(summary.tally_results, poll.state.tally_results);
+
+        // Pruning the working state of the poll retains the summary.
+        assert_ok!(Infimum::prune_poll(RuntimeOrigin::signed(0), 0));
+        assert_eq!(Infimum::finalized_poll(0), Some(summary));
+    })
+}
+
+/// Independently built polls with identical inputs should finalize with identical roots.
+#[test]
+fn poll_roots_snapshot()
+{
+    let finalize = || new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_eq!(Infimum::poll_roots(0), None);
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_eq!(Infimum::poll_roots(0).map(|(registration_root, interaction_root, _)| (registration_root, interaction_root)), Some((None, None)));
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let proof_batches = vec::Vec::from([(process_proof_data, process_commitment), (tally_proof_data, tally_commitment)]);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, get_poll_scenario(0).outcome));
+
+        let poll = Infimum::polls(0).unwrap();
+        let roots = Infimum::poll_roots(0).unwrap();
+        assert_eq!(roots, (poll.state.registrations.root, poll.state.interactions.root, poll.state.commitment));
+
+        roots
+    });
+
+    let (registration_root, interaction_root, commitment) = finalize();
+    assert!(registration_root.is_some() && interaction_root.is_some());
+    assert_eq!(commitment.verified(), 2);
+    assert_eq!(finalize(), (registration_root, interaction_root, commitment));
+}
+
+/// A tally proof should be rejected if it does not continue from the final process commitment, which seeds the tally chain.
+#[test]
+fn commit_outcome_tally_commitment_mismatch()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), vec::Vec::from([(process_proof_data, process_commitment)]), None));
+
+        let tally_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(tally_proof_data, tally_commitment)]);
+
+        // A tally chain which the caller expects to start from the zero commitment, rather than the final process commitment, is rejected.
+        assert_err_ignore_postinfo!(
+            Infimum::commit_outcome_from(RuntimeOrigin::signed(0), 1, [0u8; 32], tally_batches.clone(), None),
+            Error::<Test>::TallyCommitmentMismatch
+        );
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally, (0, [0u8; 32]));
 
-        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
-        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollVotingInProgress);
+        // The tally proof is accepted once it continues from the final process commitment.
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.current(), process_commitment);
+        assert_ok!(Infimum::commit_outcome_from(RuntimeOrigin::signed(0), 1, process_commitment, tally_batches, None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally, (1, tally_commitment));
     })
 }
 
-/// The registration tree should be able to be merged and produce the correct root and commitment value.
+/// The commitment reached by a sequence of proof batches should be derivable from their new commitments alone.
 #[test]
-fn merge_registration_state_success()
+fn commitment_with_batches()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -487,19 +3415,7 @@ fn merge_registration_state_success()
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
         assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-        assert_ok!(
-            Infimum::create_poll(
-                RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
-            )
-        );
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
 
         run_to_block(2);
 
@@ -507,45 +3423,65 @@ fn merge_registration_state_success()
         {
             assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
         }
-        
+
         run_to_block(14);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        assert_eq!(
-            Infimum::polls(0).unwrap().state.registrations.root, 
-            Some([16, 44, 202, 10, 154, 154, 255, 162, 164, 69, 231, 62, 33, 104, 15, 112, 88, 216, 113, 111, 70, 122, 146, 189, 80, 94, 79, 213, 137, 73, 176, 205])
-        );
-        assert_eq!(
-            Infimum::polls(0).unwrap().state.commitment.process,
-            (0, [42, 172, 65, 18, 133, 85, 171, 69, 236, 46, 172, 46, 31, 229, 218, 229, 163, 201, 108, 165, 174, 141, 40, 17, 128, 246, 71, 216, 46, 235, 135, 32])
-        );
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let initial = Infimum::polls(0).unwrap().state.commitment;
+        assert_eq!(initial.tally_commitment_bytes(), [0u8; 32]);
+
+        // The process batch advances the process chain, and becomes the current commitment of the next process batch.
+        let processed = initial.clone().with_batches(&[process_commitment]).unwrap();
+        assert_eq!(processed.process_commitment_bytes(), process_commitment);
+        assert_eq!(processed.tally_commitment_bytes(), initial.tally_commitment_bytes());
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), vec::Vec::from([(process_proof_data, process_commitment)]), None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment, processed);
+
+        // Once every process proof is verified, the remaining batches advance the tally chain.
+        let tallied = initial.clone().with_batches(&[process_commitment, tally_commitment]).unwrap();
+        assert_eq!(processed.clone().with_batches(&[tally_commitment]), Some(tallied.clone()));
+        assert_eq!(tallied.process_commitment_bytes(), process_commitment);
+        assert_eq!(tallied.tally_commitment_bytes(), tally_commitment);
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), vec::Vec::from([(tally_proof_data, tally_commitment)]), None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment, tallied);
+
+        // Batches in excess of the expected proofs are not derivable.
+        assert_eq!(tallied.clone().with_batches(&[]), Some(tallied.clone()));
+        assert_eq!(tallied.with_batches(&[tally_commitment]), None);
+        assert_eq!(initial.with_batches(&[process_commitment, tally_commitment, tally_commitment]), None);
     })
 }
 
-/// The registration tree should be able to be merged and produce the correct root and expected number of proofs.
+/// Stored commitments which are not canonical field elements should be rejected rather than reduced.
 #[test]
-fn merge_interaction_state_success()
+fn commit_outcome_non_canonical_commitment()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
+        let mut modulus = [0u8; 32];
+        modulus.copy_from_slice(&Fr::MODULUS.to_bytes_be());
+        let mut largest = modulus;
+        largest[31] -= 1;
+
+        assert_eq!(hashbytes_to_fr_checked(&largest), Ok(-Fr::from(1u64)));
+        assert_eq!(hashbytes_to_fr_checked(&modulus), Err(PoseidonError::InputLargerThanModulus));
+        assert_eq!(hashbytes_to_fr_checked(&[255u8; 32]), Err(PoseidonError::InputLargerThanModulus));
+
         let (alice_pk, alice_vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
         assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-        assert_ok!(
-            Infimum::create_poll(
-                RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
-            )
-        );
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
 
         run_to_block(2);
 
@@ -558,25 +3494,36 @@ fn merge_interaction_state_success()
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
         let (_pk, bob_shared_pk, message_data) = get_participant();
-
         assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
 
         run_to_block(26);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        assert_eq!(
-            Infimum::polls(0).unwrap().state.interactions.root, 
-            Some([31, 254, 7, 234, 211, 75, 174, 138, 104, 42, 237, 212, 221, 158, 115, 172, 29, 63, 109, 91, 47, 88, 77, 75, 76, 5, 201, 65, 69, 119, 219, 182])
+        let (process_proof_data, process_commitment, _tally_proof_data, _tally_commitment) = get_proof();
+        let process_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(process_proof_data, process_commitment)]);
+
+        // Simulate a process commitment which was corrupted to a value beyond the field modulus.
+        let initial = Infimum::polls(0).unwrap().state.commitment;
+        Polls::<Test>::mutate(0, |poll| {
+            poll.as_mut().unwrap().state.commitment.process.1 = [255u8; 32];
+        });
+        assert_err_ignore_postinfo!(
+            Infimum::commit_outcome(RuntimeOrigin::signed(0), process_batches.clone(), None),
+            Error::<Test>::MalformedProof { reason: VerifyError::PUBLIC_INPUTS_INVALID }
         );
 
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.expected_process, 1);
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.expected_tally, 2);
+        // The proof is accepted once the commitment is restored.
+        Polls::<Test>::mutate(0, |poll| {
+            poll.as_mut().unwrap().state.commitment = initial;
+        });
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), process_batches, None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, process_commitment));
     })
 }
 
-/// The correct public signals should be produced prior to proving.
+/// Outcomes should only be committable once the poll is over and merged, and until the outcome is determined.
 #[test]
-fn process_messages_public_signals()
+fn commit_outcome_precondition()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -585,19 +3532,8 @@ fn process_messages_public_signals()
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
         assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-        assert_ok!(
-            Infimum::create_poll(
-                RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
-            )
-        );
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert!(!Infimum::can_commit(1));
 
         run_to_block(2);
 
@@ -610,45 +3546,35 @@ fn process_messages_public_signals()
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
         let (_pk, bob_shared_pk, message_data) = get_participant();
-
         assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
 
+        // The poll is not yet over.
+        assert!(!Infimum::can_commit(0));
+
+        // The poll is over, but the interaction tree has not been merged.
         run_to_block(26);
-        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert!(Infimum::polls(0).unwrap().is_over());
+        assert!(!Infimum::can_commit(0));
 
-        // Assert validity of public proof inputs. Expected:
-        // [
-        //     "4",
-        //     "25",
-        //     "14470532103638942535012694444804587998397771818472334093141391431267230669750",
-        //     "2",
-        //     "1",
-        //     "0",
-        //     "19920653097131876015283340295735326298336825292385683485447270132525802217807",
-        //     "19301486448472428800803584456730803281486402183229406170295981014011957970720",
-        //     "15716693934388801634961548270365703551068883178466706335144577413121368892481"
-        // ]
-        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count + 1, 4);
-        assert_eq!(Infimum::polls(0).unwrap().get_voting_period_end(), 25);
-        assert_eq!(Infimum::polls(0).unwrap().state.interactions.root, Some([31, 254, 7, 234, 211, 75, 174, 138, 104, 42, 237, 212, 221, 158, 115, 172, 29, 63, 109, 91, 47, 88, 77, 75, 76, 5, 201, 65, 69, 119, 219, 182]));
-        assert_eq!(Infimum::polls(0).unwrap().state.registrations.depth, 2);
-        let mut hasher = Poseidon::<Fr>::new_circom(2).unwrap();
-        let coord_pub_key_fr: vec::Vec<Fr> = vec::Vec::from([ alice_pk.x, alice_pk.y ])
-            .iter()
-            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-            .collect();
-        let coord_pub_key_hash = hasher.hash(&coord_pub_key_fr).unwrap().into_bigint().to_string();
-        assert_eq!(coord_pub_key_hash, "19920653097131876015283340295735326298336825292385683485447270132525802217807");
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert!(Infimum::can_commit(0));
+
+        // The outcome has already been determined.
+        Polls::<Test>::mutate(0, |poll| {
+            poll.as_mut().unwrap().state.outcome = Some(0);
+        });
+        assert!(!Infimum::can_commit(0));
+        // Rejections prior to verifying any of the batches are only charged for the storage reads.
         assert_eq!(
-            Infimum::polls(0).unwrap().state.commitment.process,
-            (0, [42, 172, 65, 18, 133, 85, 171, 69, 236, 46, 172, 46, 31, 229, 218, 229, 163, 201, 108, 165, 174, 141, 40, 17, 128, 246, 71, 216, 46, 235, 135, 32])
+            Infimum::commit_outcome(RuntimeOrigin::signed(0), vec::Vec::new(), None),
+            Err(Error::<Test>::PollOutcomeAlreadyDetermined.with_weight(Infimum::commit_outcome_weight(0)))
         );
     })
 }
 
-/// A single valid message processing proof should be successfully verifiable.
+/// Proof batches should only be accepted if they continue from the stored commitment index.
 #[test]
-fn commit_outcome_single_batch()
+fn commit_outcome_commitment_index_mismatch()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -657,19 +3583,7 @@ fn commit_outcome_single_batch()
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
         assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-        assert_ok!(
-            Infimum::create_poll(
-                RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
-            )
-        );
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
 
         run_to_block(2);
 
@@ -682,24 +3596,50 @@ fn commit_outcome_single_batch()
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
         let (_pk, bob_shared_pk, message_data) = get_participant();
-
         assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
 
         run_to_block(26);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
-    
-        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None));
-    
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, new_proof_commitment));
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let process_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(process_proof_data, process_commitment)]);
+        let tally_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(tally_proof_data, tally_commitment)]);
+
+        let initial_commitment = Infimum::initial_process_commitment(0).unwrap();
+
+        // A chain which skips ahead of the stored index, or which does not continue from the stored commitment, is rejected.
+        assert_err_ignore_postinfo!(
+            Infimum::commit_outcome_from(RuntimeOrigin::signed(0), 1, initial_commitment, process_batches.clone(), None),
+            Error::<Test>::CommitmentIndexMismatch
+        );
+        assert_err_ignore_postinfo!(
+            Infimum::commit_outcome_from(RuntimeOrigin::signed(0), 0, process_commitment, process_batches.clone(), None),
+            Error::<Test>::CommitmentIndexMismatch
+        );
+
+        let initial = Infimum::polls(0).unwrap().state.commitment;
+        assert_ok!(Infimum::commit_outcome_from(RuntimeOrigin::signed(0), 0, initial_commitment, process_batches, None));
+        let processed = Infimum::polls(0).unwrap().state.commitment;
+        assert_commitment_advanced(&initial, &processed);
+        assert_eq!(processed.verified(), 1);
+
+        // A chain which restarts from a previously verified index is rejected.
+        assert_err_ignore_postinfo!(
+            Infimum::commit_outcome_from(RuntimeOrigin::signed(0), 0, process_commitment, tally_batches.clone(), None),
+            Error::<Test>::CommitmentIndexMismatch
+        );
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment, processed);
+
+        assert_ok!(Infimum::commit_outcome_from(RuntimeOrigin::signed(0), 1, process_commitment, tally_batches, None));
+        let tallied = Infimum::polls(0).unwrap().state.commitment;
+        assert_commitment_advanced(&processed, &tallied);
+        assert_eq!(tallied.verified(), 2);
     })
 }
 
-/// An invalid message processing proof should be rejected.
+/// An out of order chain of proofs should be rejected.
 #[test]
-fn commit_outcome_invalid_proof()
+fn commit_outcome_permuted()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -739,21 +3679,16 @@ fn commit_outcome_invalid_proof()
         run_to_block(26);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (_pf, new_proof_commitment, _tpf, _tc) = get_proof();
-        let proof_data = ProofData {
-            pi_a: vec::Vec::from([ 1, 90, 132, 178, 53, 72, 162, 190, 174, 234, 202, 225, 124, 15, 203, 241, 24, 166, 28, 140, 33, 166, 32, 142, 98, 204, 176, 252, 230, 140, 192, 20, 139, 39, 230, 152, 184, 129, 60, 181, 238, 20, 200, 162, 172, 120, 43, 154, 8, 140, 169, 102, 4, 146, 94, 64, 88, 220, 77, 63, 11, 46, 20, 23 ]),
-            pi_b: vec::Vec::from([ 84, 30, 183, 52, 30, 16, 193, 22, 207, 118, 249, 89, 64, 160, 107, 10, 205, 244, 52, 202, 249, 228, 234, 172, 175, 156, 23, 220, 186, 234, 66, 12, 83, 150, 12, 48, 176, 8, 107, 225, 135, 4, 133, 97, 30, 180, 200, 113, 196, 162, 63, 247, 68, 183, 181, 125, 165, 1, 27, 178, 151, 4, 100, 27, 235, 67, 144, 49, 36, 228, 17, 171, 138, 32, 78, 235, 17, 96, 110, 90, 181, 238, 134, 153, 143, 241, 126, 140, 110, 231, 89, 76, 11, 204, 229, 24, 29, 255, 158, 244, 198, 108, 64, 92, 228, 96, 63, 226, 6, 159, 93, 250, 157, 181, 97, 183, 8, 78, 34, 241, 253, 29, 119, 62, 9, 19, 207, 164 ]),
-            pi_c: vec::Vec::from([ 182, 96, 48, 82, 178, 199, 89, 110, 195, 62, 134, 21, 179, 247, 238, 14, 188, 181, 110, 68, 123, 104, 180, 13, 224, 126, 126, 197, 175, 15, 10, 21, 13, 52, 132, 172, 241, 121, 20, 152, 135, 139, 30, 106, 85, 16, 123, 212, 179, 189, 37, 237, 139, 45, 248, 83, 70, 14, 234, 82, 234, 229, 157, 8 ])
-        };
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
-    
-        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::MalformedProof);
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(tally_proof_data, tally_commitment), (process_proof_data, process_commitment)]);
+
+        assert_err_ignore_postinfo!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::MalformedProof { reason: VerifyError::PROOF_REJECTED });
     })
 }
 
-/// An valid message processing proof with an invalid commitment should be rejected.
+/// A full chain of valid proofs paired with an outcome inconsistent with the tally commitment should be rejected.
 #[test]
-fn commit_outcome_invalid_commitment()
+fn commit_outcome_mismatched_outcome()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -776,34 +3711,34 @@ fn commit_outcome_invalid_commitment()
             )
         );
 
-        run_to_block(2);
-
         for (origin, pk) in &get_participants()
         {
             assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
         }
 
-        run_to_block(14);
+        run_to_block(2 + signup_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (_pk, bob_shared_pk, message_data) = get_participant();
-
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+        let scenario = get_poll_scenario(1);
+        for (pk, data) in &scenario.interactions
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, *data));
+        }
 
-        run_to_block(26);
+        run_to_block(2 + signup_period + voting_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (proof_data, _c, _tpf, _tc) = get_proof();
-        let new_proof_commitment: HashBytes = [1, 191, 85, 98, 25, 92, 104, 227, 66, 252, 50, 63, 42, 27, 108, 81, 67, 38, 115, 38, 128, 126, 14, 99, 203, 194, 61, 124, 1, 119, 164, 65];
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
-    
-        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::MalformedProof);
+        let mut outcome = scenario.outcome.unwrap();
+        outcome.tally_results.swap(4, 5);
+
+        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), scenario.proof_batches, Some(outcome)), Error::<Test>::PollOutcomeInvalid);
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, None);
     })
 }
 
-/// A valid message processing proof with mismatched data should be rejected.
+/// Proof batches submitted beyond the expected number of proofs should be rejected.
 #[test]
-fn commit_outcome_mismatched_state()
+fn commit_outcome_excess_batch()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -826,40 +3761,46 @@ fn commit_outcome_mismatched_state()
             )
         );
 
-        run_to_block(2);
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
 
-        let bob_pk = PublicKey {
-            x: [ 1, 65, 89, 247, 81, 66, 57, 66, 160, 59, 9, 185, 3, 52, 188, 122, 132, 221, 26, 200, 129, 243, 234, 120, 128, 23, 19, 96, 94, 154, 207, 196 ],
-            y: [ 38, 38, 57, 70, 162, 8, 198, 245, 211, 231, 101, 158, 63, 226, 172, 117, 156, 26, 3, 50, 0, 241, 20, 66, 227, 150, 160, 78, 249, 106, 140, 69 ]
-        };
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, bob_pk));
+        run_to_block(2 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        run_to_block(14);
+        let scenario = get_poll_scenario(1);
+        for (pk, data) in &scenario.interactions
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, *data));
+        }
+
+        run_to_block(2 + signup_period + voting_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (_pk, bob_shared_pk, message_data) = get_participant();
+        let excess_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([scenario.proof_batches[0].clone()]);
 
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), scenario.proof_batches, None));
+        let commitment = Infimum::polls(0).unwrap().state.commitment;
 
-        run_to_block(26);
-        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_err_ignore_postinfo!(Infimum::commit_outcome(RuntimeOrigin::signed(0), excess_batches, None), Error::<Test>::ExcessProofBatch);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment, commitment);
 
-        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
-    
-        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::MalformedProof);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), vec::Vec::new(), scenario.outcome));
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
     })
 }
 
-/// A valid tally proof should successfully chain to a valid message processing proof.
+/// The declared outcome should be consistent with the recorded tally results.
 #[test]
-fn commit_outcome_full_batch()
+fn verify_declared_outcome()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
         let (alice_pk, alice_vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let num_options = vote_options.len();
 
         assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
         assert_ok!(
@@ -876,35 +3817,44 @@ fn commit_outcome_full_batch()
             )
         );
 
-        run_to_block(2);
-
         for (origin, pk) in &get_participants()
         {
             assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
         }
 
-        run_to_block(14);
+        run_to_block(2 + signup_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (_pk, bob_shared_pk, message_data) = get_participant();
-
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+        let scenario = get_poll_scenario(1);
+        for (pk, data) in &scenario.interactions
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, *data));
+        }
 
-        run_to_block(26);
+        run_to_block(2 + signup_period + voting_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(process_proof_data, process_commitment), (tally_proof_data, tally_commitment)]);
-
-        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None));
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, process_commitment));
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally, (1, tally_commitment));
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), scenario.proof_batches, None));
+        assert_eq!(Infimum::verify_declared_outcome(0), None);
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), vec::Vec::new(), scenario.outcome));
+        assert_eq!(Infimum::polls(0).unwrap().state.tally_results.unwrap().len(), num_options);
+        assert_eq!(Infimum::verify_declared_outcome(0), Some(true));
+
+        // Record an outcome whose tally is not the largest.
+        Polls::<Test>::mutate(0, |poll| {
+            let poll = poll.as_mut().unwrap();
+            let tally_results = poll.state.tally_results.clone().unwrap();
+            let max_tally_result = *tally_results.iter().max().unwrap();
+            poll.state.outcome = tally_results.iter().position(|result| *result < max_tally_result).map(|index| index as u32);
+        });
+        assert_eq!(Infimum::verify_declared_outcome(0), Some(false));
     })
 }
 
-/// A partial chain of valid proofs should be successfully verified, but not produce an outcome.
+/// Proofs should be rejected if the verifying keys were generated for a different circuit revision.
 #[test]
-fn commit_outcome_partial_success()
+fn commit_outcome_circuit_version_mismatch()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -926,38 +3876,43 @@ fn commit_outcome_partial_success()
                 vote_options
             )
         );
-
-        run_to_block(2);
+        assert_eq!(Infimum::polls(0).unwrap().config.circuit_version, 0);
 
         for (origin, pk) in &get_participants()
         {
             assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
         }
 
-        run_to_block(14);
+        run_to_block(2 + signup_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (_pk, bob_shared_pk, message_data) = get_participant();
-
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+        let scenario = get_poll_scenario(1);
+        for (pk, data) in &scenario.interactions
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, *data));
+        }
 
-        run_to_block(26);
+        run_to_block(2 + signup_period + voting_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(process_proof_data, process_commitment), (tally_proof_data, tally_commitment)]);
-        let scenario = get_poll_scenario(0);
-
-        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, scenario.outcome));
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, process_commitment));
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally, (1, tally_commitment));
+        // Simulate keys for a newer circuit revision.
+        Coordinators::<Test>::mutate(0, |coordinator| {
+            coordinator.as_mut().unwrap().verify_key.circuit_version = 1;
+        });
+        assert_err_ignore_postinfo!(Infimum::commit_outcome(RuntimeOrigin::signed(0), scenario.proof_batches.clone(), None), Error::<Test>::CircuitVersionMismatch);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.0, 0);
+
+        Coordinators::<Test>::mutate(0, |coordinator| {
+            coordinator.as_mut().unwrap().verify_key.circuit_version = 0;
+        });
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), scenario.proof_batches, scenario.outcome));
         assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
     })
 }
 
-/// An out of order chain of proofs should be rejected.
+/// Proof rejections should report the stage at which verification failed.
 #[test]
-fn commit_outcome_permuted()
+fn commit_outcome_verify_error_stages()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -965,20 +3920,8 @@ fn commit_outcome_permuted()
         let (alice_pk, alice_vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-        assert_ok!(
-            Infimum::create_poll(
-                RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
-            )
-        );
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk.clone()));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
 
         run_to_block(2);
 
@@ -991,16 +3934,48 @@ fn commit_outcome_permuted()
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
         let (_pk, bob_shared_pk, message_data) = get_participant();
-
         assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
 
         run_to_block(26);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(tally_proof_data, tally_commitment), (process_proof_data, process_commitment)]);
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data.clone(), new_proof_commitment)]);
+
+        // A verifying key which cannot be deserialized.
+        Coordinators::<Test>::mutate(0, |coordinator| {
+            coordinator.as_mut().unwrap().verify_key.process.alpha_g1 = vec::Vec::new();
+        });
+        assert_err_ignore_postinfo!(
+            Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches.clone(), None),
+            Error::<Test>::MalformedProof { reason: VerifyError::VERIFY_KEY_MALFORMED }
+        );
+
+        // A verifying key which expects a different number of public inputs.
+        Coordinators::<Test>::mutate(0, |coordinator| {
+            let verify_key = &mut coordinator.as_mut().unwrap().verify_key;
+            verify_key.process = alice_vk.process.clone();
+            verify_key.process.gamma_abc_g1.pop();
+        });
+        assert_err_ignore_postinfo!(
+            Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches.clone(), None),
+            Error::<Test>::MalformedProof { reason: VerifyError::VERIFICATION_FAILED }
+        );
 
-        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::MalformedProof);
+        // A proof which cannot be deserialized.
+        Coordinators::<Test>::mutate(0, |coordinator| {
+            coordinator.as_mut().unwrap().verify_key.process = alice_vk.process.clone();
+        });
+        let mut malformed_proof = proof_data;
+        malformed_proof.pi_c.truncate(32);
+        assert_err_ignore_postinfo!(
+            Infimum::commit_outcome(RuntimeOrigin::signed(0), vec::Vec::from([(malformed_proof, new_proof_commitment)]), None),
+            Error::<Test>::MalformedProof { reason: VerifyError::PROOF_MALFORMED }
+        );
+
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.0, 0);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, new_proof_commitment));
     })
 }
 
@@ -1035,7 +4010,7 @@ macro_rules! invoke_test_poll_scenario {
                     assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
                 }
 
-                run_to_block(1 + signup_period);
+                run_to_block(2 + signup_period);
                 assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
                 let scenario = get_poll_scenario($scenario_index);
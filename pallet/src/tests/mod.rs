@@ -1,5 +1,8 @@
 pub mod extrinsics;
 pub mod poseidon;
+pub mod state;
+#[cfg(feature = "serde")]
+pub mod serialize;
 pub mod data;
 pub mod utils;
 
@@ -9,8 +9,11 @@ use crate::hash::{
     Poseidon,
     PoseidonError,
     PoseidonHasher,
-    PoseidonBytesHasher
+    PoseidonBytesHasher,
+    MAX_X5_LEN,
+    parameters::get_poseidon_parameters
 };
+use crate::mock::Infimum;
 
 /// Check the hash of `1` as a prime field element.
 #[test]
@@ -174,6 +177,91 @@ fn empty_input()
     }
 }
 
+/// Checks that parameters exist for every supported width, and that unsupported widths are rejected.
+#[test]
+fn parameters_supported_widths()
+{
+    for width in 2..=13u8
+    {
+        let params = get_poseidon_parameters::<Fr>(width).unwrap();
+        assert_eq!(params.width, width as usize);
+    }
+
+    for width in [0u8, 1, 14, u8::MAX]
+    {
+        assert_eq!(
+            get_poseidon_parameters::<Fr>(width).err(),
+            Some(PoseidonError::InvalidWidthCircom { width: width as usize, max_limit: 13 })
+        );
+    }
+
+    assert!(Poseidon::<Fr>::new_circom(0).is_err());
+    assert!(Poseidon::<Fr>::new_circom(13).is_err());
+    assert!(Poseidon::<Fr>::new_circom(usize::MAX).is_err());
+}
+
+/// The parameters digest should be stable for a fixed width, and distinct across widths.
+#[test]
+fn parameters_digest()
+{
+    let digest = get_poseidon_parameters::<Fr>(3).unwrap().digest();
+    assert_eq!(
+        digest,
+        [113, 254, 182, 19, 238, 144, 9, 45, 251, 57, 219, 146, 234, 109, 226, 119, 195, 6, 39, 82, 103, 138, 103, 213, 59, 156, 160, 54, 212, 228, 90, 113]
+    );
+    assert_eq!(get_poseidon_parameters::<Fr>(3).unwrap().digest(), digest);
+    assert_ne!(get_poseidon_parameters::<Fr>(2).unwrap().digest(), digest);
+
+    assert_eq!(Infimum::poseidon_params_digest(3), Some(digest));
+    assert_eq!(Infimum::poseidon_params_digest(14), None);
+}
+
+/// Check that parameters with an S-box exponent other than the circom exponent are detected.
+#[test]
+fn parameters_invalid_alpha()
+{
+    let mut params = get_poseidon_parameters::<Fr>(3).unwrap();
+    assert_eq!(params.validate_circom(), Ok(()));
+
+    params.alpha = 3;
+    assert_eq!(
+        params.validate_circom(),
+        Err(PoseidonError::InvalidAlpha { alpha: 3, expected: 5 })
+    );
+}
+
+/// Check that parameters whose rounds or dimensions do not match the circom parameters of their width are detected.
+#[test]
+fn parameters_invalid_dimensions()
+{
+    for width in 2..=MAX_X5_LEN as u8
+    {
+        assert_eq!(get_poseidon_parameters::<Fr>(width).unwrap().validate_circom(), Ok(()));
+    }
+
+    let mut params = get_poseidon_parameters::<Fr>(3).unwrap();
+    params.partial_rounds = 56;
+    assert_eq!(
+        params.validate_circom(),
+        Err(PoseidonError::InvalidRoundsCircom { full_rounds: 8, partial_rounds: 56, width: 3 })
+    );
+
+    let mut params = get_poseidon_parameters::<Fr>(3).unwrap();
+    params.width = 4;
+    assert_eq!(
+        params.validate_circom(),
+        Err(PoseidonError::InvalidRoundsCircom { full_rounds: 8, partial_rounds: 57, width: 4 })
+    );
+
+    let mut params = get_poseidon_parameters::<Fr>(3).unwrap();
+    params.ark.pop();
+    assert_eq!(params.validate_circom(), Err(PoseidonError::InvalidDimensionsCircom { width: 3 }));
+
+    let mut params = get_poseidon_parameters::<Fr>(3).unwrap();
+    params.mds[0].pop();
+    assert_eq!(params.validate_circom(), Err(PoseidonError::InvalidDimensionsCircom { width: 3 }));
+}
+
 // Test cases were created with circomlibjs poseidon([1, ...]) for 1 to 16 inputs
 const CIRCOMLIBJS_TEST_CASES: [[u8; 32]; 12] = [
     [
@@ -0,0 +1,39 @@
+use frame_support::assert_ok;
+use crate::{
+    mock::*,
+    poll::{Poll, PollConfigInput}
+};
+use crate::tests::{
+    get_coordinator_data,
+    get_participants,
+    get_poll_config_input
+};
+
+/// Polls should round trip through their JSON representation, with byte arrays hex encoded.
+#[test]
+fn poll_json_round_trip()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
+        assert_ok!(Infimum::create_poll_v2(RuntimeOrigin::signed(0), PollConfigInput { metadata: Some(b"metadata".to_vec()), ..get_poll_config_input() }));
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        let poll = Infimum::polls(0).unwrap();
+        let json = serde_json::to_string(&poll).unwrap();
+        assert!(json.contains(&format!("\"metadata_hash\":\"0x{}\"", poll.config.metadata_hash.unwrap().iter().map(|b| format!("{:02x}", b)).collect::<String>())));
+        assert_eq!(serde_json::from_str::<Poll<Test>>(&json).unwrap(), poll);
+
+        let coordinator = Infimum::coordinators(0).unwrap();
+        let json = serde_json::to_string(&coordinator).unwrap();
+        assert_eq!(serde_json::from_str::<crate::poll::Coordinator<Test>>(&json).unwrap(), coordinator);
+        assert!(serde_json::from_str::<crate::poll::PublicKey>("{\"x\":\"0x00\",\"y\":\"0x00\"}").is_err());
+    })
+}
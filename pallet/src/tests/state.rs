@@ -0,0 +1,440 @@
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, PrimeField};
+use crate::hash::{Poseidon, PoseidonBytesHasher, PoseidonHasher};
+use crate::poll::{
+    AmortizedIncrementalMerkleTree,
+    MerkleTreeError,
+    NewPollState,
+    PollState,
+    provider::expected_batches,
+    state::PollStateTree,
+    zeroes::{BINARY_ZEROES, QUINARY_ZEROES, get_merkle_zeroes}
+};
+
+/// Merging a tree with more nodes at a single depth than its arity should be rejected.
+#[test]
+fn merge_oversized_subtree()
+{
+    let mut tree = PollStateTree::new(2, 4, None).unwrap();
+    tree.hashes = Vec::from([ (0, [1u8; 32]), (0, [2u8; 32]), (0, [3u8; 32]) ]);
+
+    let result = tree.merge(false);
+    assert!(matches!(result, Err(MerkleTreeError::SubtreeOversized)));
+    assert_eq!(u8::from(MerkleTreeError::SubtreeOversized), 5);
+}
+
+/// Merging a corrupted tree whose stored depth exceeds its full depth should fail rather than loop indefinitely.
+#[test]
+fn merge_corrupted_depth()
+{
+    let mut tree = PollStateTree::new(2, 2, None).unwrap();
+    tree.hashes = Vec::from([ (3, [1u8; 32]) ]);
+    assert!(matches!(tree.merge(true), Err(MerkleTreeError::MergeFailed)));
+
+    // A depth beyond the zero table is likewise rejected.
+    let mut tree = PollStateTree::new(5, 32, None).unwrap();
+    tree.hashes = Vec::from([ (35, [1u8; 32]) ]);
+    assert!(matches!(tree.merge(true), Err(MerkleTreeError::MergeFailed)));
+}
+
+/// The estimated merge cost should match the number of hashes computed by the merge.
+#[test]
+fn merge_cost_estimate()
+{
+    let mut tree = PollStateTree::new(5, 2, None).unwrap();
+    assert_eq!(tree.pending_merge_cost(true), 0);
+
+    tree = tree.insert([1u8; 32]).unwrap();
+    assert_eq!(tree.pending_merge_cost(false), 0);
+    assert_eq!(tree.pending_merge_cost(true), 2);
+
+    for i in 2..7 { tree = tree.insert([i as u8; 32]).unwrap(); }
+    assert_eq!(tree.hashes.iter().map(|&(d, _)| d).collect::<Vec<u8>>(), Vec::from([1, 0]));
+    assert_eq!(tree.pending_merge_cost(true), 2);
+
+    let tree = tree.merge(true).unwrap();
+    assert!(tree.root.is_some());
+    assert_eq!(tree.pending_merge_cost(true), 0);
+
+    let mut tree = PollStateTree::new(2, 10, Some((0, [0u8; 32]))).unwrap();
+    for i in 1..3 { tree = tree.insert([i as u8; 32]).unwrap(); }
+    assert_eq!(tree.hashes.iter().map(|&(d, _)| d).collect::<Vec<u8>>(), Vec::from([1, 0]));
+    assert_eq!(tree.pending_merge_cost(false), 2);
+    assert_eq!(tree.pending_merge_cost(true), 10);
+
+    // A single pending node exactly at the full depth is the root, and is merged without hashing.
+    let mut tree = PollStateTree::new(5, 2, None).unwrap();
+    tree.hashes = Vec::from([ (2, [1u8; 32]) ]);
+    assert_eq!(tree.pending_merge_cost(true), 0);
+    assert_eq!(tree.clone().merge(true).unwrap().root, Some([1u8; 32]));
+
+    // A node beyond the full depth is hashed for as many rounds as the merge attempts before rejecting it.
+    tree.hashes = Vec::from([ (3, [1u8; 32]) ]);
+    assert_eq!(tree.pending_merge_cost(true), 3);
+    assert!(matches!(tree.merge(true), Err(MerkleTreeError::MergeFailed)));
+
+    // A tagged tree additionally derives a zero value for each depth.
+    let tree = PollStateTree::new(5, 2, None).unwrap().with_domain_tag([1u8; 32]).insert([1u8; 32]).unwrap();
+    assert_eq!(tree.pending_merge_cost(false), 2);
+    assert_eq!(tree.pending_merge_cost(true), 4);
+}
+
+/// The merge cost of any tree permitted by its zero table should be within the bound charged for a merge.
+#[test]
+fn merge_cost_bound()
+{
+    for arity in [2, 5]
+    {
+        let depth = (get_merkle_zeroes(arity).len() - 1) as u8;
+        let mut tree = PollStateTree::new(arity, depth, None).unwrap().with_domain_tag([1u8; 32]).insert([1u8; 32]).unwrap();
+        assert_eq!(tree.pending_merge_cost(true), 2 * u32::from(depth));
+
+        // A corrupted node beyond the full depth exhausts the rounds of the merge, and so meets the bound.
+        tree.hashes = Vec::from([ (depth + 1, [1u8; 32]) ]);
+        assert_eq!(tree.pending_merge_cost(true), PollStateTree::max_merge_cost(arity));
+        assert!(PollStateTree::max_merge_cost(arity) <= PollState::max_merge_cost());
+    }
+}
+
+/// A non-zero domain tag should produce a different root than the default domain tag.
+#[test]
+fn merge_with_domain_tag()
+{
+    let mut default_tree = PollStateTree::new(5, 2, None).unwrap();
+    let mut zero_tree = PollStateTree::new(5, 2, None).unwrap().with_domain_tag([0u8; 32]);
+    let mut tagged_tree = PollStateTree::new(5, 2, None).unwrap().with_domain_tag({
+        let mut tag = [0u8; 32];
+        tag[31] = 1;
+        tag
+    });
+
+    for i in 1..7
+    {
+        default_tree = default_tree.insert([i as u8; 32]).unwrap();
+        zero_tree = zero_tree.insert([i as u8; 32]).unwrap();
+        tagged_tree = tagged_tree.insert([i as u8; 32]).unwrap();
+    }
+
+    let default_root = default_tree.merge(true).unwrap().root.unwrap();
+    let zero_root = zero_tree.merge(true).unwrap().root.unwrap();
+    let tagged_root = tagged_tree.merge(true).unwrap().root.unwrap();
+
+    assert_eq!(default_root, zero_root);
+    assert_ne!(default_root, tagged_root);
+}
+
+/// A tagged tree should pad its merge with zeroes derived under its own domain tag.
+#[test]
+fn merge_with_domain_tag_zeroes()
+{
+    let to_bytes = |element: Fr| -> [u8; 32] {
+        let bytes = element.into_bigint().to_bytes_be();
+        let mut result = [0u8; 32];
+        result[..bytes.len()].copy_from_slice(&bytes);
+        result
+    };
+    let mut tag = [0u8; 32];
+    tag[31] = 1;
+
+    let leaf = [1u8; 32];
+    let tree = PollStateTree::new(2, 2, None).unwrap().with_domain_tag(tag);
+    let root = tree.insert(leaf).unwrap().merge(true).unwrap().root.unwrap();
+
+    let mut hasher = Poseidon::<Fr>::with_domain_tag_circom(2, Fr::from_be_bytes_mod_order(&tag)).unwrap();
+    let zero = Fr::from_be_bytes_mod_order(&get_merkle_zeroes(2)[0]);
+    let left = hasher.hash(&[ Fr::from_be_bytes_mod_order(&leaf), zero ]).unwrap();
+    let right = hasher.hash(&[ zero, zero ]).unwrap();
+    let expected = hasher.hash(&[ left, right ]).unwrap();
+
+    assert_eq!(root, to_bytes(expected));
+}
+
+/// The insertion which fills the tree should be reported, and further insertions rejected.
+#[test]
+fn insert_checked_reports_fill()
+{
+    let mut tree = PollStateTree::new(5, 1, None).unwrap();
+    for i in 1..5
+    {
+        let (next, filled) = tree.insert_checked([i as u8; 32]).unwrap();
+        assert!(!filled);
+        tree = next;
+    }
+
+    let (tree, filled) = tree.insert_checked([5u8; 32]).unwrap();
+    assert!(filled);
+    assert!(tree.root.is_some());
+    assert!(matches!(tree.insert_checked([6u8; 32]), Err(MerkleTreeError::TreeAlreadyFull)));
+
+    // A tree which grows beyond its initial depth is not filled until it reaches its maximum depth.
+    let mut tree = PollStateTree::new(5, 1, None).unwrap().with_max_depth(2).unwrap();
+    for i in 1..=5
+    {
+        let (next, filled) = tree.insert_checked([i as u8; 32]).unwrap();
+        assert!(!filled);
+        tree = next;
+    }
+    assert_eq!(tree.full_depth, 2);
+}
+
+/// A tree permitted to grow should produce the same roots as a tree created at the grown depth.
+#[test]
+fn insert_grows_to_max_depth()
+{
+    let mut grown_tree = PollStateTree::new(5, 1, None).unwrap().with_max_depth(2).unwrap();
+    let mut fixed_tree = PollStateTree::new(5, 2, None).unwrap();
+
+    for i in 1..7
+    {
+        grown_tree = grown_tree.insert([i as u8; 32]).unwrap();
+        fixed_tree = fixed_tree.insert([i as u8; 32]).unwrap();
+    }
+    assert_eq!(grown_tree.full_depth, 2);
+    assert_eq!(grown_tree.root, None);
+    assert_eq!(grown_tree.clone().merge(true).unwrap().root, fixed_tree.clone().merge(true).unwrap().root);
+
+    for i in 7..26
+    {
+        grown_tree = grown_tree.insert([i as u8; 32]).unwrap();
+        fixed_tree = fixed_tree.insert([i as u8; 32]).unwrap();
+    }
+    assert_eq!(grown_tree.full_depth, 2);
+    assert!(grown_tree.root.is_some());
+    assert_eq!(grown_tree.root, fixed_tree.root);
+    assert!(matches!(grown_tree.insert([26u8; 32]), Err(MerkleTreeError::TreeAlreadyFull)));
+
+    // The maximum depth may not be lower than the initial depth.
+    assert_eq!(PollStateTree::new(5, 2, None).unwrap().with_max_depth(1).unwrap().max_depth, 2);
+}
+
+/// Each level of the zero ladders should be the hash of `arity` copies of the level below it.
+#[test]
+fn zero_ladders()
+{
+    assert_eq!(
+        Fr::from_be_bytes_mod_order(&BINARY_ZEROES[0]).into_bigint().to_string(),
+        "6769006970205099520508948723718471724660867171122235270773600567925038008762"
+    );
+    assert_eq!(
+        Fr::from_be_bytes_mod_order(&QUINARY_ZEROES[0]).into_bigint().to_string(),
+        "8370432830353022751713833565135785980866757267633941821328460903436894336785"
+    );
+
+    for arity in [2u8, 5u8]
+    {
+        let zeroes = get_merkle_zeroes(arity);
+        let mut hasher = Poseidon::<Fr>::new_circom(arity as usize).unwrap();
+        let mut current = Fr::from_be_bytes_mod_order(&zeroes[0]);
+
+        for depth in 1..zeroes.len()
+        {
+            current = hasher.hash(&vec![current; arity as usize]).unwrap();
+
+            let bytes = current.into_bigint().to_bytes_be();
+            let mut expected = [0u8; 32];
+            expected[..bytes.len()].copy_from_slice(&bytes);
+
+            assert_eq!(zeroes[depth], expected, "arity {} depth {}", arity, depth);
+        }
+    }
+}
+
+/// Each tree error should map to its documented reason code, and back.
+#[test]
+fn merkle_tree_error_codes()
+{
+    let documented = [
+        (MerkleTreeError::TreeAlreadyFull, MerkleTreeError::TREE_ALREADY_FULL, 1),
+        (MerkleTreeError::TreeAlreadyMerged, MerkleTreeError::TREE_ALREADY_MERGED, 2),
+        (MerkleTreeError::HashFailed, MerkleTreeError::HASH_FAILED, 3),
+        (MerkleTreeError::MergeFailed, MerkleTreeError::MERGE_FAILED, 4),
+        (MerkleTreeError::SubtreeOversized, MerkleTreeError::SUBTREE_OVERSIZED, 5),
+        (MerkleTreeError::TreeFrozen, MerkleTreeError::TREE_FROZEN, 6),
+        (MerkleTreeError::TreeParametersInvalid, MerkleTreeError::TREE_PARAMETERS_INVALID, 7)
+    ];
+
+    for (error, constant, code) in documented
+    {
+        assert_eq!(constant, code);
+        assert_eq!(u8::from(MerkleTreeError::from_code(code).unwrap()), code);
+        assert_eq!(u8::from(error), code);
+    }
+
+    assert!(MerkleTreeError::from_code(0).is_none());
+    assert!(MerkleTreeError::from_code(8).is_none());
+}
+
+/// Trees which can never be merged, or which would hold only a single leaf, should not be constructed.
+#[test]
+fn state_tree_invalid_parameters()
+{
+    assert!(matches!(PollStateTree::new(0, 4, None), Err(MerkleTreeError::TreeParametersInvalid)));
+    assert!(matches!(PollStateTree::new(1, 4, None), Err(MerkleTreeError::TreeParametersInvalid)));
+    assert!(matches!(PollStateTree::new(2, 0, None), Err(MerkleTreeError::TreeParametersInvalid)));
+    assert!(matches!(PollStateTree::new(5, 0, Some((0, [0u8; 32]))), Err(MerkleTreeError::TreeParametersInvalid)));
+    assert!(PollStateTree::new(2, 1, None).is_ok());
+
+    // Trees may not be constructed, nor grow, beyond the depth of the zero tables which pad them during a merge.
+    for arity in [2, 5]
+    {
+        let depth = get_merkle_zeroes(arity).len() as u8;
+        assert!(matches!(PollStateTree::new(arity, depth, None), Err(MerkleTreeError::TreeParametersInvalid)));
+        assert!(matches!(PollStateTree::new(arity, 1, None).unwrap().with_max_depth(depth), Err(MerkleTreeError::TreeParametersInvalid)));
+        assert!(PollStateTree::new(arity, depth - 1, None).is_ok());
+        assert!(PollStateTree::new(arity, 1, None).unwrap().with_max_depth(depth - 1).is_ok());
+    }
+
+    // The poll state propagates the error of either tree.
+    assert!(matches!(PollState::new(0, 2), Err(MerkleTreeError::TreeParametersInvalid)));
+    assert!(matches!(PollState::new(4, 0), Err(MerkleTreeError::TreeParametersInvalid)));
+    assert!(PollState::new(4, 2).is_ok());
+}
+
+/// Computes the root of a tree of the given arity and depth directly, padding the leaves with zeroes.
+fn full_tree_root(
+    arity: u8,
+    depth: u8,
+    leaves: &[[u8; 32]]
+) -> [u8; 32]
+{
+    let mut hasher = Poseidon::<Fr>::new_circom(arity as usize).unwrap();
+    let zero = Fr::from_be_bytes_mod_order(&get_merkle_zeroes(arity)[0]);
+
+    let mut level: Vec<Fr> = leaves
+        .iter()
+        .map(|leaf| Fr::from_be_bytes_mod_order(leaf))
+        .collect();
+    level.resize((arity as usize).pow(depth as u32), zero);
+
+    while level.len() > 1
+    {
+        level = level
+            .chunks(arity as usize)
+            .map(|children| hasher.hash(children).unwrap())
+            .collect();
+    }
+
+    let bytes = level[0].into_bigint().to_bytes_be();
+    let mut root = [0u8; 32];
+    root[..bytes.len()].copy_from_slice(&bytes);
+    root
+}
+
+/// Inserting any number of leaves up to the tree capacity and merging should produce the same root as
+/// computing the full tree directly. The leaf counts include the subtree boundaries, and a pseudorandom sample.
+#[test]
+fn insert_merge_matches_full_tree()
+{
+    let mut seed: u64 = 0x1f2e3d4c5b6a7988;
+
+    for arity in [2u8, 5u8]
+    {
+        for depth in 1..=4u8
+        {
+            let capacity = (arity as u32).pow(depth as u32);
+
+            let mut counts = Vec::from([ 1, arity as u32 - 1, arity as u32, arity as u32 + 1, capacity - 1, capacity ]);
+            for _ in 0..8
+            {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                counts.push(((seed >> 33) as u32 % capacity) + 1);
+            }
+
+            for count in counts.into_iter().filter(|count| (1..=capacity).contains(count))
+            {
+                let leaves: Vec<[u8; 32]> = (1..=count)
+                    .map(|i| {
+                        let bytes = Fr::from(i as u64).into_bigint().to_bytes_be();
+                        let mut leaf = [0u8; 32];
+                        leaf[..bytes.len()].copy_from_slice(&bytes);
+                        leaf
+                    })
+                    .collect();
+
+                let mut tree = PollStateTree::new(arity, depth, None).unwrap();
+                for leaf in &leaves { tree = tree.insert(*leaf).unwrap(); }
+                assert_eq!(tree.count, count);
+
+                // A tree filled to capacity is merged upon the final insertion.
+                let tree = if tree.root.is_some() { tree } else { tree.merge(true).unwrap() };
+
+                assert_eq!(
+                    tree.root,
+                    Some(full_tree_root(arity, depth, &leaves)),
+                    "arity {} depth {} count {}", arity, depth, count
+                );
+            }
+        }
+    }
+}
+
+/// A registration tree holding a single registration alongside the reserved zero leaf should keep a consistent
+/// count and depth, and merge to the root of a tree with one real leaf and zeroes elsewhere.
+#[test]
+fn merge_single_registration_leaf()
+{
+    let zero = get_merkle_zeroes(2)[0];
+    let leaf = [1u8; 32];
+
+    let tree = PollState::new(4, 2).unwrap().registrations;
+    assert_eq!(tree.hashes, Vec::from([ (0, zero) ]));
+    assert_eq!((tree.count, tree.depth), (0, 0));
+
+    let tree = tree.insert(leaf).unwrap();
+    assert_eq!((tree.count, tree.depth), (1, 1));
+    assert_eq!(tree.root, None);
+
+    // The registration tree is merged to its true depth.
+    let merged = tree.clone().merge(false).unwrap();
+    assert_eq!(merged.root, Some(full_tree_root(2, 1, &[zero, leaf])));
+    assert_eq!((merged.count, merged.depth), (1, 1));
+
+    let merged = tree.merge(true).unwrap();
+    assert_eq!(merged.root, Some(full_tree_root(2, 4, &[zero, leaf])));
+}
+
+/// The state trees should hash exclusively with the in-crate circom Poseidon, such that their roots match the circuits.
+#[test]
+fn state_tree_hash_is_circom_poseidon()
+{
+    let state = PollState::new(4, 2).unwrap();
+
+    for tree in [state.registrations, state.interactions]
+    {
+        let inputs: Vec<[u8; 32]> = (1..=tree.arity).map(|i| [i; 32]).collect();
+        let slices: Vec<&[u8]> = inputs.iter().map(|input| input.as_slice()).collect();
+
+        let mut hasher = Poseidon::<Fr>::new_circom(tree.arity as usize).unwrap();
+        assert_eq!(tree.hash(inputs.clone()).unwrap(), hasher.hash_bytes_be(&slices).unwrap());
+    }
+}
+
+/// Trees whose subtrees exceed the circom Poseidon width should be rejected at construction.
+#[test]
+fn state_tree_supported_arity()
+{
+    assert!(PollStateTree::new(2, 4, None).is_ok());
+    assert!(PollStateTree::new(5, 4, None).is_ok());
+    assert!(PollStateTree::new(12, 2, None).is_ok());
+    assert!(matches!(PollStateTree::new(13, 2, None), Err(MerkleTreeError::TreeParametersInvalid)));
+}
+
+/// The number of proof batches should round up to cover any partial final batch.
+#[test]
+fn expected_batch_counts()
+{
+    // Exact multiples of the batch size.
+    assert_eq!(expected_batches(0, 1, 5), 0);
+    assert_eq!(expected_batches(5, 1, 5), 1);
+    assert_eq!(expected_batches(10, 1, 5), 2);
+    assert_eq!(expected_batches(50, 2, 5), 2);
+
+    // A remainder requires an additional batch.
+    assert_eq!(expected_batches(1, 1, 5), 1);
+    assert_eq!(expected_batches(11, 1, 5), 3);
+    assert_eq!(expected_batches(4, 1, 2), 2);
+    assert_eq!(expected_batches(5, 1, 2), 3);
+
+    // A subtree of depth zero processes a single leaf per batch.
+    assert_eq!(expected_batches(7, 0, 5), 7);
+}
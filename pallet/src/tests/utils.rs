@@ -1,5 +1,6 @@
 use crate::{
-    mock::*
+    mock::*,
+    poll::Commitment
 };
 use frame_support::pallet_prelude::Hooks;
 
@@ -17,3 +18,11 @@ pub fn run_to_block(n: u64)
         Infimum::on_initialize(System::block_number());
     }
 }
+
+/// Asserts that neither of the commitment chains regressed between `before` and `after`.
+pub fn assert_commitment_advanced(before: &Commitment, after: &Commitment)
+{
+    assert!(after.process.0 >= before.process.0, "process commitment index regressed");
+    assert!(after.tally.0 >= before.tally.0, "tally commitment index regressed");
+    assert!(after.verified() >= before.verified());
+}
@@ -0,0 +1,30 @@
+//! Weights for the computationally expensive operations of the pallet.
+//!
+//! NB the pallet has not yet been benchmarked. The weights of the `()` implementation are conservative placeholders, and
+//! runtimes should supply weights measured on their own hardware before relying on them in production.
+
+use frame_support::weights::Weight;
+
+/// The weights of the operations whose cost is not dominated by storage access.
+pub trait WeightInfo
+{
+    /// The cost of verifying a single groth16 proof, including the computation of its public inputs.
+    fn verify_proof() -> Weight;
+
+    /// The cost of computing a single Poseidon hash whilst merging a state tree.
+    fn merge_hash() -> Weight;
+}
+
+/// Placeholder weights, pending benchmarks.
+impl WeightInfo for ()
+{
+    fn verify_proof() -> Weight
+    {
+        Weight::from_parts(10_000_000_000, 0)
+    }
+
+    fn merge_hash() -> Weight
+    {
+        Weight::from_parts(50_000_000, 0)
+    }
+}